@@ -2,7 +2,7 @@ use bevy::{
     ecs::system::{EntityCommand, EntityCommands},
     prelude::*,
 };
-use motiongfx_core::{prelude::*, UpdateSequenceSet};
+use motiongfx_core::prelude::*;
 
 pub mod motion;
 
@@ -19,22 +19,17 @@ pub struct MotionGfxCommonPlugin;
 
 impl Plugin for MotionGfxCommonPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (
-                update_component::<Transform, Vec3>,
-                update_component::<Transform, Quat>,
-                update_component::<Transform, f32>,
-                update_component::<Sprite, Color>,
-                update_component::<Sprite, f32>,
-                update_asset::<StandardMaterial, Color>,
-                update_asset::<StandardMaterial, LinearRgba>,
-                update_asset::<StandardMaterial, f32>,
-                update_asset::<ColorMaterial, Color>,
-                update_asset::<ColorMaterial, f32>,
-            )
-                .in_set(UpdateSequenceSet),
-        );
+        app.register_motion::<Transform, Transform>()
+            .register_motion::<Transform, Vec3>()
+            .register_motion::<Transform, Quat>()
+            .register_motion::<Transform, f32>()
+            .register_motion::<Sprite, Color>()
+            .register_motion::<Sprite, f32>()
+            .register_asset_motion::<StandardMaterial, Color>()
+            .register_asset_motion::<StandardMaterial, LinearRgba>()
+            .register_asset_motion::<StandardMaterial, f32>()
+            .register_asset_motion::<ColorMaterial, Color>()
+            .register_asset_motion::<ColorMaterial, f32>();
     }
 }
 
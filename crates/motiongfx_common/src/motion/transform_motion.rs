@@ -101,4 +101,45 @@ impl<'a> TransformMotionBuilder<'a> {
             end = rotation,
         )
     }
+
+    /// Translate by `offset` as an [additive](Action::additive) action,
+    /// so that it sums up with other overlapping relative actions.
+    pub fn translate_by(&mut self, offset: Vec3) -> Action<Vec3, Transform> {
+        self.transform.translation += offset;
+
+        Action::new_f32lerp(self.id, Vec3::ZERO, offset, |transform: &mut Transform| {
+            &mut transform.translation
+        })
+        .with_field_path("translation")
+        .additive()
+    }
+
+    /// Rotate by `rotation` as an [additive](Action::with_additive) action,
+    /// so that it sums up with other overlapping relative actions.
+    pub fn rotate_by(&mut self, rotation: Quat) -> Action<Quat, Transform> {
+        self.transform.rotation = rotation * self.transform.rotation;
+
+        Action::new_f32lerp(
+            self.id,
+            Quat::IDENTITY,
+            rotation,
+            |transform: &mut Transform| &mut transform.rotation,
+        )
+        .with_field_path("rotation")
+        .with_additive(|value, prev, next| *next * prev.inverse() * *value)
+    }
+
+    /// Multiply the scale by `scale` as an [additive](Action::with_additive) action,
+    /// so that it sums up with other overlapping relative actions.
+    ///
+    /// None of the components of `scale` should be zero.
+    pub fn scale_by(&mut self, scale: Vec3) -> Action<Vec3, Transform> {
+        self.transform.scale *= scale;
+
+        Action::new_f32lerp(self.id, Vec3::ONE, scale, |transform: &mut Transform| {
+            &mut transform.scale
+        })
+        .with_field_path("scale")
+        .with_additive(|value, prev, next| *value / *prev * *next)
+    }
 }
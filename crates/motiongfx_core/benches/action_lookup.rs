@@ -0,0 +1,114 @@
+//! Measures the cost of a single scrubbing update on sequences of increasing size.
+//!
+//! Each update only touches a handful of actions, so with the interval index the cost
+//! should stay roughly flat as the number of actions grows, instead of growing linearly.
+//! The `lookup` group compares the index against the linear scan it replaced on the same data.
+
+use bevy::prelude::*;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use motiongfx_core::{prelude::*, MotionGfxCorePlugin};
+
+#[derive(Component, Clone, Copy)]
+struct Value(f32);
+
+/// Creates an [`App`] with a chained [`Sequence`] of `action_count` actions,
+/// returning the [`Entity`] that holds the [`SequenceController`].
+fn setup_app(action_count: usize) -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, MotionGfxCorePlugin))
+        .register_motion::<Value, f32>();
+
+    let world = app.world_mut();
+    let mut commands = world.commands();
+
+    let mut sequences = Vec::with_capacity(action_count);
+    for _ in 0..action_count {
+        let mut value = Value(0.0);
+        let id = commands.spawn(value).id();
+
+        sequences.push(
+            commands.play_motion(act!((id, Value), start = { value }.0, end = 1.0,).animate(0.1)),
+        );
+    }
+
+    let sequence_id = commands
+        .spawn(SequenceBundle::from_sequence(sequences.chain()))
+        .id();
+    world.flush();
+
+    (app, sequence_id)
+}
+
+fn scrub(c: &mut Criterion) {
+    let mut group = c.benchmark_group("scrub");
+
+    for action_count in [1_000, 10_000, 100_000] {
+        let (mut app, sequence_id) = setup_app(action_count);
+        // Scrub back and forth around the middle of the sequence.
+        let middle = action_count as f32 * 0.05;
+        let mut forward = true;
+
+        group.bench_with_input(
+            BenchmarkId::from_parameter(action_count),
+            &action_count,
+            |b, _| {
+                b.iter(|| {
+                    let mut sequence_controller = app
+                        .world_mut()
+                        .get_mut::<SequenceController>(sequence_id)
+                        .unwrap();
+
+                    sequence_controller.target_time = match forward {
+                        true => middle + 1.0 / 60.0,
+                        false => middle,
+                    };
+                    forward = !forward;
+
+                    app.update();
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lookup");
+
+    for action_count in [1_000, 10_000, 100_000] {
+        let (app, sequence_id) = setup_app(action_count);
+        let sequence = app.world().get::<Sequence>(sequence_id).unwrap();
+        // Same time range as a single scrubbing update in the middle of the sequence.
+        let begin = action_count as f32 * 0.05;
+        let end = begin + 1.0 / 60.0;
+
+        group.bench_with_input(
+            BenchmarkId::new("index", action_count),
+            &action_count,
+            |b, _| {
+                b.iter(|| {
+                    sequence
+                        .query_action_indices(black_box(begin), black_box(end))
+                        .count()
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("linear_scan", action_count),
+            &action_count,
+            |b, _| {
+                b.iter(|| {
+                    sequence
+                        .scan_action_indices(black_box(begin), black_box(end))
+                        .count()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, scrub, lookup);
+criterion_main!(benches);
@@ -0,0 +1,455 @@
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{
+    bracketed, parenthesized,
+    parse::{discouraged::Speculative, Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token::{Brace, Bracket, Paren},
+    Error, Expr, Ident, Index, LitFloat, LitInt, Member, Result, Token, Type,
+};
+
+use crate::motiongfx_core_path;
+
+/// Input of [`act!`](crate::act).
+pub(crate) struct Act {
+    target_id: Expr,
+    target_ty: Type,
+    root: Expr,
+    path: FieldPath,
+    end: End,
+    interp: Option<Expr>,
+    ease: Option<Expr>,
+    duration: Option<Expr>,
+}
+
+/// End value of a single field, or the end values of several fields relative to the start path.
+enum End {
+    Value(Expr),
+    Fields(Vec<(FieldPath, Expr)>),
+}
+
+/// Chain of fields, indices and method calls, e.g. `.points[2].x` or `.points_mut()[2]`.
+#[derive(Clone, Default)]
+struct FieldPath {
+    segments: Vec<Segment>,
+    span: Option<Span>,
+}
+
+#[derive(Clone)]
+enum Segment {
+    Field(Member),
+    Index(Expr),
+    Method(Ident, Vec<Expr>),
+}
+
+impl Parse for Act {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let target_id = content.parse::<Expr>()?;
+        content.parse::<Token![,]>()?;
+        let target_ty = content.parse::<Type>()?;
+        content.parse::<Option<Token![,]>>()?;
+
+        let mut start = None;
+        let mut end = None;
+        let mut interp = None;
+        let mut ease = None;
+        let mut duration = None;
+
+        while input.is_empty() == false {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let key = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+
+            let duplicate = match key.to_string().as_str() {
+                "start" => start.replace(parse_start(input)?).is_some(),
+                "end" => end.replace(parse_end(input)?).is_some(),
+                "interp" => interp.replace(input.parse::<Expr>()?).is_some(),
+                "ease" => ease.replace(input.parse::<Expr>()?).is_some(),
+                "duration" => duration.replace(input.parse::<Expr>()?).is_some(),
+                _ => {
+                    return Err(Error::new(
+                        key.span(),
+                        format!(
+                            "unknown argument `{key}`, expected `start`, `end`, `interp`, `ease` or `duration`"
+                        ),
+                    ));
+                }
+            };
+
+            if duplicate {
+                return Err(Error::new(key.span(), format!("duplicate `{key}`")));
+            }
+        }
+
+        let Some((root, path)) = start else {
+            return Err(Error::new(Span::call_site(), "missing `start = { value }`"));
+        };
+        let Some(end) = end else {
+            return Err(Error::new(Span::call_site(), "missing `end = value`"));
+        };
+
+        // A single interpolation can not take the different types of several fields.
+        if let (Some(interp), End::Fields(fields)) = (&interp, &end) {
+            if fields.len() > 1 {
+                return Err(Error::new_spanned(
+                    interp,
+                    "`interp` can only be used with a single field, \
+                    use `Action::with_interp` on each action instead",
+                ));
+            }
+        }
+
+        Ok(Self {
+            target_id,
+            target_ty,
+            root,
+            path,
+            end,
+            interp,
+            ease,
+            duration,
+        })
+    }
+}
+
+/// Parse `{ root }.path`.
+fn parse_start(input: ParseStream) -> Result<(Expr, FieldPath)> {
+    if input.peek(Brace) == false {
+        return Err(input.error(
+            "expected the start value inside braces, e.g. `start = { transform }.translation`",
+        ));
+    }
+
+    let content;
+    syn::braced!(content in input);
+    let root = content.parse::<Expr>()?;
+    if content.is_empty() == false {
+        return Err(content.error("expected a single expression"));
+    }
+
+    Ok((root, input.parse::<FieldPath>()?))
+}
+
+/// Parse `value` or `{ .path = value, .. }`.
+fn parse_end(input: ParseStream) -> Result<End> {
+    // A block expression can not start with a `.`.
+    if input.peek(Brace) {
+        let fork = input.fork();
+        let content;
+        syn::braced!(content in fork);
+
+        if content.peek(Token![.]) {
+            let fields = Punctuated::<(FieldPath, Expr), Token![,]>::parse_terminated_with(
+                &content,
+                |input| {
+                    let path = input.parse::<FieldPath>()?;
+                    input.parse::<Token![=]>()?;
+                    Ok((path, input.parse::<Expr>()?))
+                },
+            )?;
+
+            input.advance_to(&fork);
+            return Ok(End::Fields(fields.into_iter().collect()));
+        }
+    }
+
+    Ok(End::Value(input.parse::<Expr>()?))
+}
+
+impl Parse for FieldPath {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut path = FieldPath {
+            span: Some(input.span()),
+            ..Default::default()
+        };
+
+        loop {
+            if input.peek(Bracket) {
+                let content;
+                bracketed!(content in input);
+                path.segments.push(Segment::Index(content.parse()?));
+            } else if input.peek(Token![.]) {
+                input.parse::<Token![.]>()?;
+
+                if input.peek(Ident) && input.peek2(Paren) {
+                    let method = input.parse::<Ident>()?;
+                    let content;
+                    parenthesized!(content in input);
+                    let args = Punctuated::<Expr, Token![,]>::parse_terminated(&content)?;
+                    path.segments
+                        .push(Segment::Method(method, args.into_iter().collect()));
+                } else if input.peek(Ident) {
+                    path.segments
+                        .push(Segment::Field(Member::Named(input.parse()?)));
+                } else if input.peek(LitInt) {
+                    let lit = input.parse::<LitInt>()?;
+                    path.segments.push(Segment::Field(tuple_index(
+                        lit.base10_digits(),
+                        lit.suffix(),
+                        lit.span(),
+                    )?));
+                } else if input.peek(LitFloat) {
+                    // `.0.1` is lexed as `.` followed by the float `0.1`.
+                    let lit = input.parse::<LitFloat>()?;
+                    let Some((a, b)) = lit.base10_digits().split_once('.') else {
+                        return Err(Error::new(lit.span(), "expected a tuple index"));
+                    };
+
+                    for index in [a, b] {
+                        path.segments.push(Segment::Field(tuple_index(
+                            index,
+                            lit.suffix(),
+                            lit.span(),
+                        )?));
+                    }
+                } else {
+                    return Err(input.error("expected a field, tuple index or method after `.`"));
+                }
+            } else {
+                break;
+            }
+        }
+
+        Ok(path)
+    }
+}
+
+fn tuple_index(digits: &str, suffix: &str, span: Span) -> Result<Member> {
+    match (digits.parse::<u32>(), suffix.is_empty()) {
+        (Ok(index), true) => Ok(Member::Unnamed(Index { index, span })),
+        _ => Err(Error::new(span, "expected a tuple index")),
+    }
+}
+
+impl FieldPath {
+    fn join(&self, other: &FieldPath) -> FieldPath {
+        let mut segments = self.segments.clone();
+        segments.extend(other.segments.iter().cloned());
+
+        FieldPath {
+            segments,
+            span: other.span.or(self.span),
+        }
+    }
+
+    /// Path used for conflict detection, only static paths of fields and literal indices have one.
+    fn field_path(&self) -> Option<String> {
+        let mut field_path = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Field(member) => {
+                    if field_path.is_empty() == false {
+                        field_path.push('.');
+                    }
+
+                    match member {
+                        Member::Named(ident) => field_path.push_str(&ident.to_string()),
+                        Member::Unnamed(index) => field_path.push_str(&index.index.to_string()),
+                    }
+                }
+                Segment::Index(Expr::Lit(lit)) => {
+                    field_path.push_str(&format!("[{}]", lit.to_token_stream()));
+                }
+                _ => return None,
+            }
+        }
+
+        Some(field_path)
+    }
+}
+
+/// Values inside a [`FieldPath`] that are evaluated once and cloned into every use.
+#[derive(Default)]
+struct Hoisted {
+    lets: Vec<TokenStream2>,
+}
+
+impl Hoisted {
+    /// Returns the expression as is if it is a literal, otherwise hoist it into a local.
+    fn hoist(&mut self, expr: &Expr) -> TokenStream2 {
+        if let Expr::Lit(_) = expr {
+            return expr.to_token_stream();
+        }
+
+        let ident = Ident::new(&format!("arg_{}", self.lets.len()), Span::mixed_site());
+        self.lets.push(quote!(let #ident = #expr;));
+        quote!(::core::clone::Clone::clone(&#ident))
+    }
+}
+
+pub(crate) fn act(input: Act) -> TokenStream2 {
+    let core = motiongfx_core_path();
+    let Act {
+        target_id,
+        target_ty,
+        root,
+        path,
+        end,
+        interp,
+        ease,
+        duration,
+    } = input;
+
+    let fields = match end {
+        End::Value(value) => vec![(path, value)],
+        End::Fields(fields) => fields
+            .into_iter()
+            .map(|(field_path, value)| (path.join(&field_path), value))
+            .collect(),
+    };
+    let single = fields.len() == 1;
+
+    let target = Ident::new("target_id", Span::mixed_site());
+    let mut hoisted = Hoisted::default();
+    let mut ends = Vec::new();
+    let mut starts = Vec::new();
+    let mut assigns = Vec::new();
+    let mut actions = Vec::new();
+
+    // Shared arguments are evaluated once when there are multiple fields.
+    // They are all `Copy` (function pointers and `f32`), so no clones are needed.
+    let mut shared = Vec::new();
+    let mut share = |name: &str, expr: Option<Expr>| {
+        let expr = expr?;
+        match single {
+            true => Some(expr.to_token_stream()),
+            false => {
+                let ident = Ident::new(name, Span::mixed_site());
+                shared.push(quote!(let #ident = #expr;));
+                Some(ident.to_token_stream())
+            }
+        }
+    };
+    let interp = share("interp", interp);
+    let ease = share("ease", ease);
+    let duration = share("duration", duration);
+
+    for (i, (field_path, value)) in fields.iter().enumerate() {
+        let hoisted_count = hoisted.lets.len();
+        let segments = field_path
+            .segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Field(member) => quote!(.#member),
+                Segment::Index(index) => {
+                    let index = hoisted.hoist(index);
+                    quote!([#index])
+                }
+                Segment::Method(method, args) => {
+                    let args = args.iter().map(|arg| hoisted.hoist(arg));
+                    quote!(.#method(#(#args),*))
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let path_span = match field_path.segments.is_empty() {
+            true => root.span(),
+            false => field_path.span.unwrap_or_else(Span::call_site),
+        };
+
+        let source = Ident::new("source", Span::mixed_site());
+        let mut root_place = quote!((#root));
+        let mut source_place = quote!((*#source));
+        for (segment, tokens) in field_path.segments.iter().zip(&segments) {
+            match segment {
+                // Methods return a mutable reference that has to be dereferenced.
+                Segment::Method(..) => {
+                    root_place = quote!((*#root_place #tokens));
+                    source_place = quote!((*#source_place #tokens));
+                }
+                _ => {
+                    root_place = quote!(#root_place #tokens);
+                    source_place = quote!(#source_place #tokens);
+                }
+            }
+        }
+
+        // Hygienic locals that point at the user's code in errors.
+        let start = format_ident!("start_{i}", span = Span::mixed_site().located_at(path_span));
+        let end = format_ident!(
+            "end_{i}",
+            span = Span::mixed_site().located_at(value.span())
+        );
+
+        ends.push(quote!(let #end = #value;));
+        starts.push(quote!(let #start = ::core::clone::Clone::clone(&#root_place);));
+        assigns.push(quote_spanned! {value.span()=>
+            #[allow(unused_assignments)]
+            {
+                #root_place = ::core::clone::Clone::clone(&#end);
+            }
+        });
+
+        let captures_args = hoisted.lets.len() > hoisted_count;
+        let get_field = match captures_args {
+            true => quote!(move |#source: &mut #target_ty| &mut #source_place),
+            // Plain function pointers take the fast path.
+            false => quote! {{
+                let get_field: #core::action::GetFieldMut<_, #target_ty> =
+                    |#source: &mut #target_ty| &mut #source_place;
+                get_field
+            }},
+        };
+
+        // Unsatisfied bounds are reported at the field path.
+        let mut spanned_core = core.clone();
+        if let Some(leading_colon) = &mut spanned_core.leading_colon {
+            leading_colon.spans = [path_span; 2];
+        }
+        for segment in spanned_core.segments.iter_mut() {
+            segment.ident.set_span(path_span);
+        }
+        let mut action = match &interp {
+            Some(interp) => quote_spanned! {path_span=>
+                #spanned_core::action::Action::new(
+                    #target,
+                    #start,
+                    #end,
+                    {
+                        let interp: #core::action::InterpFn<_> = #interp;
+                        interp
+                    },
+                    #get_field,
+                )
+            },
+            None => quote_spanned! {path_span=>
+                #spanned_core::action::Action::new_f32lerp(#target, #start, #end, #get_field)
+            },
+        };
+        if let Some(field_path) = field_path.field_path() {
+            action = quote!(#action.with_field_path(#field_path));
+        }
+        if let Some(ease) = &ease {
+            action = quote!(#action.with_ease_fn(#ease));
+        }
+        if let Some(duration) = &duration {
+            action = quote!(#action.animate(#duration));
+        }
+        actions.push(action);
+    }
+
+    let lets = &hoisted.lets;
+    let actions = match single {
+        true => quote!(#(#actions)*),
+        false => quote!((#(#actions),*)),
+    };
+
+    quote! {
+        {
+            let #target = #target_id;
+            #(#lets)*
+            #(#ends)*
+            #(#shared)*
+            #(#starts)*
+            #(#assigns)*
+            #actions
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, parse_quote_spanned, Data, DeriveInput, Error, Fields, Index, Member, Path,
+    Result, Type,
+};
+
+use crate::motiongfx_core_path;
+
+/// How a single field is interpolated.
+enum FieldLerp {
+    F32Lerp,
+    Skip,
+    Step,
+    With(Path),
+}
+
+impl FieldLerp {
+    fn parse(attrs: &[syn::Attribute]) -> Result<Self> {
+        let mut field_lerp = FieldLerp::F32Lerp;
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("f32lerp")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    field_lerp = FieldLerp::Skip;
+                } else if meta.path.is_ident("step") {
+                    field_lerp = FieldLerp::Step;
+                } else if meta.path.is_ident("with") {
+                    field_lerp = FieldLerp::With(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("expected `skip`, `step` or `with = path`"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(field_lerp)
+    }
+}
+
+/// Patterns binding the fields of a struct or variant as `start_*` and `end_*`, and the interpolated construction.
+struct VariantLerp {
+    start_pat: TokenStream2,
+    end_pat: TokenStream2,
+    construct: TokenStream2,
+}
+
+pub(crate) fn derive_f32lerp(mut input: DeriveInput) -> Result<TokenStream2> {
+    let core = motiongfx_core_path();
+
+    let mut bounds = Vec::new();
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let VariantLerp {
+                start_pat,
+                end_pat,
+                construct,
+            } = variant_lerp(&core, &parse_quote!(Self), &data.fields, &mut bounds)?;
+
+            quote! {
+                let #start_pat = self;
+                let #end_pat = rhs;
+                #construct
+            }
+        }
+        Data::Enum(data) => {
+            let mut arms = Vec::new();
+            for variant in &data.variants {
+                let ident = &variant.ident;
+                let VariantLerp {
+                    start_pat,
+                    end_pat,
+                    construct,
+                } = variant_lerp(
+                    &core,
+                    &parse_quote!(Self::#ident),
+                    &variant.fields,
+                    &mut bounds,
+                )?;
+
+                arms.push(quote!((#start_pat, #end_pat) => #construct,));
+            }
+
+            quote! {
+                // Different variants can not be blended.
+                #[allow(unreachable_patterns)]
+                match (self, rhs) {
+                    #(#arms)*
+                    _ => #core::f32lerp::step(self, rhs, t),
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`F32Lerp` can not be derived for unions.",
+            ));
+        }
+    };
+
+    // Only generic types need the bounds, as concrete field types are checked anyway.
+    if input.generics.params.is_empty() == false {
+        let where_clause = input.generics.make_where_clause();
+        for bound in bounds {
+            where_clause.predicates.push(bound);
+        }
+    }
+
+    // Stepping between different variants clones the whole value,
+    // the bound reports a missing `Clone` on the enum itself.
+    if let Data::Enum(_) = &input.data {
+        let span = input.ident.span();
+        input
+            .generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote_spanned!(span=> Self: ::core::clone::Clone));
+    }
+
+    let ty = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #core::f32lerp::F32Lerp for #ty #ty_generics #where_clause {
+            fn f32lerp(&self, rhs: &Self, t: f32) -> Self {
+                #body
+            }
+        }
+    })
+}
+
+fn variant_lerp(
+    core: &Path,
+    path: &Path,
+    fields: &Fields,
+    bounds: &mut Vec<syn::WherePredicate>,
+) -> Result<VariantLerp> {
+    let mut members = Vec::new();
+    let mut starts = Vec::new();
+    let mut ends = Vec::new();
+    let mut lerps = Vec::new();
+
+    for (i, field) in fields.iter().enumerate() {
+        let start = format_ident!("start_{i}");
+        let end = format_ident!("end_{i}");
+        let mut end_pat = quote!(#end);
+        let ty = &field.ty;
+
+        let lerp = match FieldLerp::parse(&field.attrs)? {
+            FieldLerp::F32Lerp => {
+                bounds.push(parse_quote!(#ty: #core::f32lerp::F32Lerp));
+                quote!(#core::f32lerp::F32Lerp::f32lerp(#start, #end, t))
+            }
+            FieldLerp::Skip => {
+                // Ignored in the pattern to avoid an unused binding.
+                end_pat = quote!(_);
+                bounds.push(clone_bound(ty));
+                quote!(::core::clone::Clone::clone(#start))
+            }
+            FieldLerp::Step => {
+                bounds.push(clone_bound(ty));
+                quote!(#core::f32lerp::step(#start, #end, t))
+            }
+            FieldLerp::With(with) => quote!(#with(#start, #end, t)),
+        };
+
+        members.push(match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(i)),
+        });
+        starts.push(start);
+        ends.push(end_pat);
+        lerps.push(lerp);
+    }
+
+    Ok(VariantLerp {
+        start_pat: quote!(#path { #(#members: #starts),* }),
+        end_pat: quote!(#path { #(#members: #ends),* }),
+        construct: quote!(#path { #(#members: #lerps),* }),
+    })
+}
+
+fn clone_bound(ty: &Type) -> syn::WherePredicate {
+    parse_quote!(#ty: ::core::clone::Clone)
+}
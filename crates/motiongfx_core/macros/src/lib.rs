@@ -1,15 +1,21 @@
 use std::str::FromStr;
 
+use bevy_macro_utils::BevyManifest;
 use proc_macro::TokenStream;
 use proc_macro2::{Punct, Spacing, Span, TokenStream as TokenStream2};
+use proc_macro_crate::{crate_name, FoundCrate};
 use quote::{quote, TokenStreamExt};
 use syn::{
     parse::{Parse, ParseStream},
     parse_macro_input,
     token::Comma,
-    Ident, LitInt, Result,
+    DeriveInput, Ident, LitInt, Result,
 };
 
+mod act;
+mod f32lerp;
+mod motion_target;
+
 struct CombinationTuple {
     macro_ident: Ident,
     count: usize,
@@ -71,3 +77,65 @@ pub fn tuple_combinations(input: TokenStream) -> TokenStream {
 
     tokens.into()
 }
+
+/// Generates a `<Type>Motion` builder trait with a `to_<field>` method for every field.
+///
+/// Deriving does not register anything by itself, register every generated action type
+/// with a single `app.register_motion_target::<Type>()` call.
+///
+/// Add `#[motion_target(asset)]` for assets and `#[motion_target(skip)]` for fields that can not be animated.
+/// Every animated field must implement `F32Lerp` and `Clone`.
+#[proc_macro_derive(MotionTarget, attributes(motion_target))]
+pub fn derive_motion_target(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    motion_target::derive_motion_target(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Creates `Action`s and changes the animated values to their end values.
+///
+/// See `motiongfx_core::action::act` for the full syntax.
+#[proc_macro]
+pub fn act(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as act::Act);
+
+    act::act(input).into()
+}
+
+/// Implements `F32Lerp` by interpolating every field.
+///
+/// Fields can be marked with `#[f32lerp(skip)]` to keep the start value,
+/// `#[f32lerp(step)]` to jump to the end value halfway through,
+/// or `#[f32lerp(with = path)]` to use a custom `fn(&T, &T, f32) -> T`.
+///
+/// Enums interpolate within the same variant and step between different variants,
+/// so they must implement `Clone`.
+#[proc_macro_derive(F32Lerp, attributes(f32lerp))]
+pub fn derive_f32lerp(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    f32lerp::derive_f32lerp(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Path to `motiongfx_core`, either directly or through `bevy_motiongfx`.
+fn motiongfx_core_path() -> syn::Path {
+    match crate_name("motiongfx_core") {
+        Ok(FoundCrate::Name(name)) => BevyManifest::parse_str(&format!("::{name}")),
+        // `motiongfx_core` declares itself as an extern crate.
+        Ok(FoundCrate::Itself) => BevyManifest::parse_str("::motiongfx_core"),
+        Err(_) => match crate_name("bevy_motiongfx") {
+            Ok(FoundCrate::Name(name)) => {
+                BevyManifest::parse_str(&format!("::{name}::motiongfx_core"))
+            }
+            _ => BevyManifest::parse_str("::motiongfx_core"),
+        },
+    }
+}
+
+fn bevy_path() -> syn::Path {
+    BevyManifest::default().get_path("bevy")
+}
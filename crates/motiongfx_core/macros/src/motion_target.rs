@@ -0,0 +1,162 @@
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Ident, Index, Member, Result};
+
+use crate::{bevy_path, motiongfx_core_path};
+
+/// Whether the derived type is a `Component` or an `Asset`.
+enum MotionKind {
+    Component,
+    Asset,
+}
+
+pub(crate) fn derive_motion_target(input: DeriveInput) -> Result<TokenStream2> {
+    let core = motiongfx_core_path();
+    let bevy = bevy_path();
+
+    if input.generics.params.is_empty() == false {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "`MotionTarget` can not be derived for generic types.",
+        ));
+    }
+
+    let mut kind = MotionKind::Component;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("motion_target"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("asset") {
+                kind = MotionKind::Asset;
+                Ok(())
+            } else {
+                Err(meta.error("expected `asset`"))
+            }
+        })?;
+    }
+
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`MotionTarget` can only be derived for structs.",
+        ));
+    };
+
+    let vis = &input.vis;
+    let ty = &input.ident;
+    let method = Ident::new(&to_snake_case(&ty.to_string()), Span::call_site());
+    let trait_ident = format_ident!("{ty}Motion");
+    let builder_ident = format_ident!("{ty}MotionBuilder");
+
+    let mut to_fns = Vec::new();
+    let mut field_tys = Vec::new();
+    for (i, field) in data.fields.iter().enumerate() {
+        let mut skip = false;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("motion_target"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    skip = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `skip`"))
+                }
+            })?;
+        }
+
+        if skip {
+            continue;
+        }
+
+        let (member, to_fn) = match &field.ident {
+            Some(ident) => (Member::Named(ident.clone()), format_ident!("to_{ident}")),
+            None => (Member::Unnamed(Index::from(i)), format_ident!("to_{i}")),
+        };
+        let field_ty = &field.ty;
+
+        to_fns.push(quote! {
+            pub fn #to_fn(&mut self, value: #field_ty) -> #core::action::Action<#field_ty, #ty> {
+                #core::action::act!(
+                    (self.id, #ty),
+                    start = { self.#method }.#member,
+                    end = value,
+                )
+            }
+        });
+        field_tys.push(field_ty);
+    }
+
+    let register_fn = match kind {
+        MotionKind::Component => quote!(register_component),
+        MotionKind::Asset => quote!(register_asset),
+    };
+
+    let trait_doc = format!("Creates a [`{builder_ident}`] from an `(Entity, ..)` tuple.");
+    let builder_doc = format!("Builds actions that animate the fields of a [`{ty}`].");
+
+    Ok(quote! {
+        #[doc = #trait_doc]
+        #vis trait #trait_ident<const N: usize> {
+            fn #method(&mut self) -> #builder_ident<'_>;
+        }
+
+        impl<const N: usize, T: #core::tuple_motion::GetMutValue<#ty, N>> #trait_ident<N>
+            for (#bevy::ecs::entity::Entity, T)
+        {
+            fn #method(&mut self) -> #builder_ident<'_> {
+                #builder_ident::new(
+                    #core::tuple_motion::GetId::id(self),
+                    #core::tuple_motion::GetMutValue::get_mut_value(&mut self.1),
+                )
+            }
+        }
+
+        #[doc = #builder_doc]
+        #vis struct #builder_ident<'a> {
+            pub id: #bevy::ecs::entity::Entity,
+            pub #method: &'a mut #ty,
+        }
+
+        impl<'a> #builder_ident<'a> {
+            pub fn new(id: #bevy::ecs::entity::Entity, #method: &'a mut #ty) -> Self {
+                Self { id, #method }
+            }
+
+            #(#to_fns)*
+        }
+
+        impl #core::registry::MotionTarget for #ty {
+            fn register(registry: &mut #core::registry::MotionRegistry) {
+                #(registry.#register_fn::<#ty, #field_tys>();)*
+            }
+        }
+    })
+}
+
+/// Converts an `UpperCamelCase` type name into a `snake_case` method name.
+fn to_snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut snake_case = String::with_capacity(name.len() + 4);
+
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_lowercase = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next_lowercase)
+            {
+                snake_case.push('_');
+            }
+        }
+
+        snake_case.extend(c.to_lowercase());
+    }
+
+    snake_case
+}
@@ -1,19 +1,163 @@
+use std::{
+    any::{Any, TypeId},
+    ops::{Add, Sub},
+    sync::Arc,
+};
+
 use bevy::prelude::*;
 
 use crate::{
-    ease::{cubic, EaseFn},
+    ease::{self, cubic, Ease, EaseFn},
     f32lerp::F32Lerp,
     prelude::MultiSeqOrd,
-    sequence::Sequence,
+    sequence::{
+        time_warp::{first_time, last_time, TimeClip, TimeWarp},
+        Sequence,
+    },
+    stagger::Stagger,
 };
 
 /// Function for interpolating a type based on a [`f32`] time.
 pub type InterpFn<T> = fn(start: &T, end: &T, t: f32) -> T;
 /// Function for getting a mutable reference of a field (or itself) of type `T` in type `U`.
 pub type GetFieldMut<T, U> = fn(source: &mut U) -> &mut T;
+/// Shared closure version of [`InterpFn`].
+pub type InterpClosure<T> = Arc<dyn Fn(&T, &T, f32) -> T + Send + Sync>;
+/// Shared closure version of [`GetFieldMut`].
+pub type GetFieldClosure<T, U> = Arc<dyn Fn(&mut U) -> &mut T + Send + Sync>;
+/// Function for replacing the `prev` contribution of an additive [`Action`] inside `value` with `next`.
+pub type CombineFn<T> = fn(value: &T, prev: &T, next: &T) -> T;
+
+/// An interpolation function that is either a plain [`InterpFn`] or a shared closure that can capture state.
+pub enum Interp<T> {
+    /// Plain function pointer (fast path).
+    Fn(InterpFn<T>),
+    /// Shared closure.
+    Closure(InterpClosure<T>),
+}
+
+impl<T: 'static> Interp<T> {
+    /// Creates a new [`Interp`] from a plain [`InterpFn`] (fast path).
+    pub const fn from_fn(interp_fn: InterpFn<T>) -> Self {
+        Self::Fn(interp_fn)
+    }
+
+    /// Creates a new [`Interp`] from any interpolation function.
+    ///
+    /// Only values that are already an [`InterpFn`] take the fast path, use [`Interp::from_fn`]
+    /// for function items.
+    pub fn new<F>(interp_fn: F) -> Self
+    where
+        F: Fn(&T, &T, f32) -> T + Send + Sync + 'static,
+    {
+        match (&interp_fn as &dyn Any).downcast_ref::<InterpFn<T>>() {
+            Some(interp_fn) => Self::Fn(*interp_fn),
+            None => Self::Closure(Arc::new(interp_fn)),
+        }
+    }
+}
+
+impl<T> Interp<T> {
+    /// Interpolate between `start` and `end` based on a [`f32`] time.
+    #[inline]
+    pub fn interp(&self, start: &T, end: &T, t: f32) -> T {
+        match self {
+            Self::Fn(interp_fn) => interp_fn(start, end, t),
+            Self::Closure(interp_fn) => interp_fn(start, end, t),
+        }
+    }
+}
+
+impl<T> Clone for Interp<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Fn(interp_fn) => Self::Fn(*interp_fn),
+            Self::Closure(interp_fn) => Self::Closure(interp_fn.clone()),
+        }
+    }
+}
+
+/// A field getter that is either a plain [`GetFieldMut`] or a shared closure that can capture state.
+pub enum GetField<T, U> {
+    /// Plain function pointer (fast path).
+    Fn(GetFieldMut<T, U>),
+    /// Shared closure.
+    Closure(GetFieldClosure<T, U>),
+}
+
+impl<T: 'static, U: 'static> GetField<T, U> {
+    /// Creates a new [`GetField`] from a plain [`GetFieldMut`] (fast path).
+    pub const fn from_fn(get_field_fn: GetFieldMut<T, U>) -> Self {
+        Self::Fn(get_field_fn)
+    }
+
+    /// Creates a new [`GetField`] from any field getter.
+    ///
+    /// Only values that are already a [`GetFieldMut`] take the fast path, use [`GetField::from_fn`]
+    /// for function items and non-capturing closures.
+    pub fn new<F>(get_field_fn: F) -> Self
+    where
+        F: Fn(&mut U) -> &mut T + Send + Sync + 'static,
+    {
+        match (&get_field_fn as &dyn Any).downcast_ref::<GetFieldMut<T, U>>() {
+            Some(get_field_fn) => Self::Fn(*get_field_fn),
+            None => Self::Closure(Arc::new(get_field_fn)),
+        }
+    }
+}
+
+impl<T, U> GetField<T, U> {
+    /// Get a mutable reference of the field from `source`.
+    #[inline]
+    pub fn get_mut<'a>(&self, source: &'a mut U) -> &'a mut T {
+        match self {
+            Self::Fn(get_field_fn) => get_field_fn(source),
+            Self::Closure(get_field_fn) => get_field_fn(source),
+        }
+    }
+}
+
+impl<T, U> Clone for GetField<T, U> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Fn(get_field_fn) => Self::Fn(*get_field_fn),
+            Self::Closure(get_field_fn) => Self::Closure(get_field_fn.clone()),
+        }
+    }
+}
+
+/// How the value of an [`Action`] is written to its field.
+#[derive(Clone)]
+pub enum Layer<T> {
+    /// Overwrite the field with the value of the action.
+    Override,
+    /// Combine the change in value since the last update with the field,
+    /// so that overlapping additive actions on the same field sum up regardless of order.
+    Additive {
+        /// Function for combining the change in value with the field.
+        combine_fn: CombineFn<T>,
+    },
+}
 
 /// Creates an [`Action`] and changes the animated value to the end value.
 ///
+/// The start value is read from `start = { root }.path` and the field is set to `end` right away,
+/// so the next action continues from there. The path can go through fields, tuple indices,
+/// array indices (`[2]`) and methods that return a mutable reference (`.points_mut()`).
+/// Paths with non-literal indices or methods are not checked for [conflicts](crate::conflict).
+///
+/// Optional arguments:
+/// - `interp = fn` overwrites the default [`F32Lerp`] interpolation with an [`InterpFn`].
+/// - `ease = fn` sets the [easing function](Action::with_ease_fn) to an [`EaseFn`].
+/// - `duration = seconds` turns the [`Action`] into a [`Motion`].
+///
+/// Closures that capture state can be set afterwards with [`Action::with_interp`] and [`Action::with_ease`].
+///
+/// Several fields can be animated at once with `end = { .path = value, .. }`,
+/// where each path continues from the start path. This creates a tuple of actions
+/// that share the `ease` and `duration` arguments. `interp` is rejected for several fields,
+/// as they can have different types.
+///
 /// # Example
 ///
 /// ```rust
@@ -31,97 +175,70 @@ pub type GetFieldMut<T, U> = fn(source: &mut U) -> &mut T;
 ///     start = { transform }.translation.x,
 ///     end = transform.translation.x + 1.0,
 /// );
+/// assert_eq!(transform.translation.x, 1.0);
+///
+/// // Animates `translation.y` and `scale` together.
+/// let (translation_y, scale) = act!(
+///     (id, Transform),
+///     start = { transform },
+///     end = {
+///         .translation.y = 2.0,
+///         .scale = Vec3::splat(2.0),
+///     },
+///     ease = ease::quad::ease_out,
+///     duration = 1.0,
+/// );
+/// assert_eq!(transform.scale, Vec3::splat(2.0));
+///
+/// #[derive(Component, Clone)]
+/// struct Polyline {
+///     points: [Vec2; 3],
+///     colors: Vec<(Color, f32)>,
+/// }
+///
+/// let mut polyline = Polyline {
+///     points: [Vec2::ZERO; 3],
+///     colors: vec![(Color::WHITE, 1.0)],
+/// };
+/// let index = 0;
+///
+/// let point = act!(
+///     (id, Polyline),
+///     start = { polyline }.points[2].x,
+///     end = 1.0,
+/// );
+/// let alpha = act!(
+///     (id, Polyline),
+///     start = { polyline }.colors[index].1,
+///     end = 0.5,
+///     interp = |start: &f32, end: &f32, t: f32| f32::lerp(*start, *end, t * t),
+/// );
+/// assert_eq!(polyline.points[2].x, 1.0);
+/// assert_eq!(polyline.colors[0].1, 0.5);
 /// ```
-#[macro_export]
-macro_rules! act {
-    (
-        ($target_id:expr, $comp_ty:ty),
-        start = { $root:expr }.$($path:tt).+,
-        end = $value:expr,
-    ) => {
-        {
-            let action = $crate::action::Action::new_f32lerp(
-                $target_id,
-                $root.$($path).+.clone(),
-                $value.clone(),
-                |source: &mut $comp_ty| &mut source.$($path).+,
-            );
-
-            $root.$($path).+ = $value;
-
-            action
-        }
-    };
-    (
-        ($target_id:expr, $comp_ty:ty),
-        start = { $root:expr },
-        end = $value:expr,
-    ) => {
-        {
-            let action = $crate::action::Action::new_f32lerp(
-                $target_id,
-                $root.clone(),
-                $value.clone(),
-                |source: &mut $comp_ty| source,
-            );
-
-            #[allow(unused_assignments)]
-            {
-                $root = $value;
-            }
-
-            action
-        }
-    };
-    (
-        ($target_id:expr, $comp_ty:ty),
-        start = { $root:expr }.$($path:tt).+,
-        end = $value:expr,
-        interp = $interp:expr,
-    ) => {
-        {
-            let action = $crate::action::Action::new(
-                $target_id,
-                $root.$($path).+.clone(),
-                $value.clone(),
-                |source: &mut $comp_ty| &mut source.$($path).+,
-                $interp,
-            );
-
-            $root.$($path).+ = $value;
-
-            action
-        }
-    };
-    (
-        ($target_id:expr, $comp_ty:ty),
-        start = { $root:expr },
-        end = $value:expr,
-        interp = $interp:expr,
-    ) => {
-        {
-            let action = $crate::action::Action::new_f32lerp(
-                $target_id,
-                $root.clone(),
-                $value.clone(),
-                |source: &mut $comp_ty| source,
-                $interp,
-            );
-
-            #[allow(unused_assignments)]
-            {
-                $root = $value;
-            }
-
-            action
-        }
-    };
-}
-
-pub use act;
+///
+/// ```compile_fail
+/// use bevy::prelude::*;
+/// use motiongfx_core::prelude::*;
+///
+/// let mut world = World::new();
+/// let mut transform = Transform::default();
+/// let id = world.spawn(transform).id();
+///
+/// let (translation_y, scale) = act!(
+///     (id, Transform),
+///     start = { transform },
+///     end = {
+///         .translation.y = 2.0,
+///         .scale = Vec3::splat(2.0),
+///     },
+///     interp = |start: &f32, end: &f32, t: f32| f32::lerp(*start, *end, t),
+/// );
+/// ```
+pub use motiongfx_core_macros::act;
 
 /// Basic data structure to describe an animation action.
-#[derive(Component, Clone, Copy)]
+#[derive(Component, Clone)]
 pub struct Action<T, U> {
     /// Target [`Entity`] for [`Component`] manipulation.
     pub(crate) target_id: Entity,
@@ -130,44 +247,180 @@ pub struct Action<T, U> {
     /// Final value of the action.
     pub(crate) end: T,
     /// Function for getting a mutable reference of a field (or itself) from the component.
-    pub(crate) get_field_fn: GetFieldMut<T, U>,
+    pub(crate) get_field_fn: GetField<T, U>,
     /// Function for interpolating the value based on a [`f32`] time.
-    pub(crate) interp_fn: InterpFn<T>,
+    pub(crate) interp_fn: Interp<T>,
     /// Function for easing the [`f32`] time value for the action.
-    pub(crate) ease_fn: EaseFn,
+    pub(crate) ease_fn: Ease,
+    /// How the value is written to the field.
+    pub(crate) layer: Layer<T>,
+    /// Path of the field inside the component, used for detecting conflicting actions.
+    pub(crate) field_path: Option<&'static str>,
 }
 
-impl<T, U> Action<T, U> {
+impl<T, U> Action<T, U>
+where
+    T: 'static,
+    U: 'static,
+{
     /// Creates a new [`Action`].
-    pub fn new(
-        target_id: Entity,
-        start: T,
-        end: T,
-        interp_fn: InterpFn<T>,
-        get_field_fn: GetFieldMut<T, U>,
-    ) -> Self {
+    ///
+    /// Both `interp_fn` and `get_field_fn` can either be plain functions or closures that capture state.
+    /// Use [`with_interp_fn`](Self::with_interp_fn) and [`with_field_fn`](Self::with_field_fn)
+    /// afterwards for the fast path.
+    pub fn new<I, F>(target_id: Entity, start: T, end: T, interp_fn: I, get_field_fn: F) -> Self
+    where
+        I: Fn(&T, &T, f32) -> T + Send + Sync + 'static,
+        F: Fn(&mut U) -> &mut T + Send + Sync + 'static,
+    {
         Self {
             target_id,
             start,
             end,
-            get_field_fn,
-            interp_fn,
-            ease_fn: cubic::ease_in_out,
+            get_field_fn: GetField::new(get_field_fn),
+            interp_fn: Interp::new(interp_fn),
+            ease_fn: Ease::Fn(cubic::ease_in_out),
+            layer: Layer::Override,
+            field_path: None,
         }
     }
 
-    /// Overwrite the existing [easing function](EaseFn).
-    pub fn with_ease(mut self, ease_fn: EaseFn) -> Self {
-        self.ease_fn = ease_fn;
+    /// Overwrite the existing [easing function](Ease).
+    ///
+    /// Function items such as `ease::quad::ease_in` have their own type and are stored as
+    /// closures here. Pass them to [`with_ease_fn`](Self::with_ease_fn) instead for the fast path.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use motiongfx_core::prelude::*;
+    ///
+    /// let action = Action::new_f32lerp(
+    ///     Entity::PLACEHOLDER,
+    ///     0.0,
+    ///     1.0,
+    ///     |transform: &mut Transform| &mut transform.translation.x,
+    /// );
+    ///
+    /// // Plain easing function
+    /// let action = action.with_ease_fn(ease::quad::ease_in);
+    /// // Closure that captures state
+    /// let steps = 4.0;
+    /// let action = action.with_ease(move |t| f32::floor(t * steps) / steps);
+    /// ```
+    pub fn with_ease<E>(mut self, ease_fn: E) -> Self
+    where
+        E: Fn(f32) -> f32 + Send + Sync + 'static,
+    {
+        self.ease_fn = Ease::new(ease_fn);
+        self
+    }
+
+    /// Overwrite the existing [easing function](Ease) with a plain [`EaseFn`] (fast path).
+    pub fn with_ease_fn(mut self, ease_fn: EaseFn) -> Self {
+        self.ease_fn = Ease::from_fn(ease_fn);
+        self
+    }
+
+    /// Overwrite the existing [interpolation function](Interp).
+    ///
+    /// Function items are stored as closures here, use [`with_interp_fn`](Self::with_interp_fn)
+    /// for the fast path.
+    pub fn with_interp<I>(mut self, interp_fn: I) -> Self
+    where
+        I: Fn(&T, &T, f32) -> T + Send + Sync + 'static,
+    {
+        self.interp_fn = Interp::new(interp_fn);
+        self
+    }
+
+    /// Overwrite the existing [interpolation function](Interp) with a plain [`InterpFn`] (fast path).
+    pub fn with_interp_fn(mut self, interp_fn: InterpFn<T>) -> Self {
+        self.interp_fn = Interp::from_fn(interp_fn);
         self
     }
 
-    /// Overwrite the existing [interpolation function](InterpFn).
-    pub fn with_interp(mut self, interp_fn: InterpFn<T>) -> Self {
-        self.interp_fn = interp_fn;
+    /// Overwrite the existing [field getter](GetField).
+    ///
+    /// Function items and non-capturing closures are stored as closures here,
+    /// use [`with_field_fn`](Self::with_field_fn) for the fast path.
+    ///
+    /// This clears the [field path](Self::with_field_path).
+    pub fn with_field<F>(mut self, get_field_fn: F) -> Self
+    where
+        F: Fn(&mut U) -> &mut T + Send + Sync + 'static,
+    {
+        self.get_field_fn = GetField::new(get_field_fn);
+        self.field_path = None;
         self
     }
 
+    /// Overwrite the existing [field getter](GetField) with a plain [`GetFieldMut`] (fast path).
+    ///
+    /// This clears the [field path](Self::with_field_path).
+    pub fn with_field_fn(mut self, get_field_fn: GetFieldMut<T, U>) -> Self {
+        self.get_field_fn = GetField::from_fn(get_field_fn);
+        self.field_path = None;
+        self
+    }
+
+    /// Set the path of the field inside the component (e.g. `"translation.x"`, or `""` for the component itself).
+    ///
+    /// Actions with a field path are checked for [conflicts](crate::conflict).
+    /// [`act!`] sets the field path automatically.
+    pub fn with_field_path(mut self, field_path: &'static str) -> Self {
+        self.field_path = Some(field_path);
+        self
+    }
+
+    /// Write the value as an [additive layer](Layer::Additive) using a custom `combine_fn`.
+    ///
+    /// The `start` and `end` values describe the contribution of the action
+    /// and `start` should be the identity of `combine_fn` (e.g. zero for addition).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use motiongfx_core::prelude::*;
+    ///
+    /// // Rotate by 90 degrees on top of any other rotation.
+    /// let action = Action::new_f32lerp(
+    ///     Entity::PLACEHOLDER,
+    ///     Quat::IDENTITY,
+    ///     Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+    ///     |transform: &mut Transform| &mut transform.rotation,
+    /// )
+    /// .with_additive(|value, prev, next| *next * prev.inverse() * *value);
+    /// ```
+    pub fn with_additive(mut self, combine_fn: CombineFn<T>) -> Self {
+        self.layer = Layer::Additive { combine_fn };
+        self
+    }
+
+    /// Sample the action at a given (un-eased) unit time.
+    ///
+    /// Additive actions only contribute the change in value since `prev_unit_time`.
+    pub(crate) fn sample(&self, prev_unit_time: f32, unit_time: f32) -> LayerValue<T> {
+        let value = self.value_at(unit_time);
+
+        match &self.layer {
+            Layer::Override => LayerValue::Override(value),
+            Layer::Additive { combine_fn } => LayerValue::Additive {
+                combine_fn: *combine_fn,
+                prev: self.value_at(prev_unit_time),
+                next: value,
+            },
+        }
+    }
+
+    /// Interpolated value at a given (un-eased) unit time.
+    fn value_at(&self, unit_time: f32) -> T {
+        let t = self.ease_fn.ease(unit_time);
+        self.interp_fn.interp(&self.start, &self.end, t)
+    }
+
     /// Convert an [`Action`] into a [`Motion`] by adding a duration.
     pub fn animate(self, duration: f32) -> Motion<T, U> {
         Motion {
@@ -175,49 +428,239 @@ impl<T, U> Action<T, U> {
             duration,
         }
     }
+
+    /// Convert an [`Action`] into a [`Motion`] that follows a damped [spring](crate::ease::spring).
+    ///
+    /// The duration is the time it takes for the spring to settle.
+    /// Underdamped springs overshoot the end value, which requires an
+    /// [interpolation function](Interp) that extrapolates, like [`F32Lerp`] on numbers and vectors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `damping` is not positive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use motiongfx_core::prelude::*;
+    ///
+    /// let motion = Action::new_f32lerp(
+    ///     Entity::PLACEHOLDER,
+    ///     0.0,
+    ///     1.0,
+    ///     |transform: &mut Transform| &mut transform.translation.x,
+    /// )
+    /// .spring(170.0, 26.0, 1.0);
+    ///
+    /// assert!(motion.duration > 0.0);
+    /// ```
+    pub fn spring(self, stiffness: f32, damping: f32, mass: f32) -> Motion<T, U> {
+        let (ease_fn, duration) = ease::spring(stiffness, damping, mass);
+        self.with_ease(ease_fn).animate(duration)
+    }
 }
 
 impl<T, U> Action<T, U>
 where
-    T: F32Lerp,
+    T: Add<Output = T> + Sub<Output = T> + Clone + 'static,
+    U: 'static,
+{
+    /// Write the value as an [additive layer](Layer::Additive) using addition.
+    ///
+    /// The `start` and `end` values describe the offset added by the action, `start` is usually zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use motiongfx_core::{prelude::*, MotionGfxCorePlugin};
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins((MinimalPlugins, MotionGfxCorePlugin))
+    ///     .register_motion::<Transform, f32>();
+    ///
+    /// let id = app.world_mut().spawn(Transform::default()).id();
+    ///
+    /// let offset = |end: f32| {
+    ///     Action::new_f32lerp(id, 0.0, end, |transform: &mut Transform| {
+    ///         &mut transform.translation.x
+    ///     })
+    ///     .with_ease_fn(ease::linear)
+    ///     .additive()
+    ///     .animate(1.0)
+    /// };
+    ///
+    /// // 2 overlapping actions on the same field.
+    /// let mut commands = app.world_mut().commands();
+    /// let sequence = [commands.play_motion(offset(1.0)), commands.play_motion(offset(2.0))].all();
+    /// let sequence_id = commands.spawn(SequenceBundle::from_sequence(sequence)).id();
+    /// app.world_mut().flush();
+    ///
+    /// for (time, x) in [(0.5, 1.5), (1.0, 3.0), (0.25, 0.75), (0.0, 0.0)] {
+    ///     let mut controller = app.world_mut().get_mut::<SequenceController>(sequence_id).unwrap();
+    ///     controller.target_time = time;
+    ///     app.update();
+    ///
+    ///     let transform = app.world().get::<Transform>(id).unwrap();
+    ///     assert!(f32::abs(transform.translation.x - x) < 1e-5);
+    /// }
+    /// ```
+    ///
+    /// [Seeking](crate::sequence::SequenceController::seek) rewinds the contributions of
+    /// additive actions, including the ones that have not started yet, so the result
+    /// only depends on the seeked time.
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use motiongfx_core::{prelude::*, MotionGfxCorePlugin};
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins((MinimalPlugins, MotionGfxCorePlugin))
+    ///     .register_motion::<Transform, f32>();
+    ///
+    /// let mut transform = Transform::default();
+    /// let id = app.world_mut().spawn(transform).id();
+    ///
+    /// let offset = Action::new_f32lerp(id, 0.0, 1.0, |transform: &mut Transform| {
+    ///     &mut transform.translation.x
+    /// })
+    /// .with_ease_fn(ease::linear)
+    /// .additive()
+    /// .animate(1.0);
+    /// transform.translation.x += 1.0;
+    ///
+    /// let mut commands = app.world_mut().commands();
+    /// let sequence = [
+    ///     Sequence::default().delay(0.5),
+    ///     commands.play_motion(offset),
+    ///     commands.play_motion(
+    ///         act!((id, Transform), start = { transform }.translation.x, end = 3.0,)
+    ///             .with_ease_fn(ease::linear)
+    ///             .animate(1.0),
+    ///     ),
+    /// ]
+    /// .chain();
+    /// let sequence_id = commands.spawn(SequenceBundle::from_sequence(sequence)).id();
+    /// app.world_mut().flush();
+    ///
+    /// // Scrub past the additive action first.
+    /// let mut controller = app.world_mut().get_mut::<SequenceController>(sequence_id).unwrap();
+    /// controller.target_time = 2.0;
+    /// app.update();
+    ///
+    /// for (time, x) in [(0.0, 0.0), (1.0, 0.5), (2.0, 2.0), (1.25, 0.75), (0.25, 0.0), (2.5, 3.0)] {
+    ///     let mut controller = app.world_mut().get_mut::<SequenceController>(sequence_id).unwrap();
+    ///     controller.seek(time);
+    ///     app.update();
+    ///
+    ///     let transform = app.world().get::<Transform>(id).unwrap();
+    ///     assert!(f32::abs(transform.translation.x - x) < 1e-5);
+    /// }
+    /// ```
+    pub fn additive(self) -> Self {
+        self.with_additive(|value, prev, next| value.clone() - prev.clone() + next.clone())
+    }
+}
+
+impl<T, U> Action<T, U>
+where
+    T: F32Lerp + 'static,
+    U: 'static,
 {
     /// Creates a new [`Action`] with [`F32Lerp`] as the default
-    /// [interpolation function](InterpFn).
-    pub fn new_f32lerp(
-        target_id: Entity,
-        start: T,
-        end: T,
-        get_field_fn: GetFieldMut<T, U>,
-    ) -> Self {
+    /// [interpolation function](Interp).
+    pub fn new_f32lerp<F>(target_id: Entity, start: T, end: T, get_field_fn: F) -> Self
+    where
+        F: Fn(&mut U) -> &mut T + Send + Sync + 'static,
+    {
         Self {
             target_id,
             start,
             end,
-            get_field_fn,
-            interp_fn: T::f32lerp,
-            ease_fn: cubic::ease_in_out,
+            get_field_fn: GetField::new(get_field_fn),
+            interp_fn: Interp::Fn(T::f32lerp),
+            ease_fn: Ease::Fn(cubic::ease_in_out),
+            layer: Layer::Override,
+            field_path: None,
         }
     }
 }
 
-#[derive(Clone, Copy)]
+/// A sampled value of an [`Action`] that is ready to be written to its field.
+pub(crate) enum LayerValue<T> {
+    Override(T),
+    Additive {
+        combine_fn: CombineFn<T>,
+        prev: T,
+        next: T,
+    },
+}
+
+impl<T> LayerValue<T> {
+    /// Write the value to the `field`.
+    #[inline]
+    pub fn write(self, field: &mut T) {
+        match self {
+            Self::Override(value) => *field = value,
+            Self::Additive {
+                combine_fn,
+                prev,
+                next,
+            } => *field = combine_fn(field, &prev, &next),
+        }
+    }
+}
+
+#[derive(Clone)]
 pub(crate) struct ActionMeta {
     /// Target `Entity` for `Action`.
     action_id: Entity,
+    /// [`TypeId`] of the `Action` for looking up its applier.
+    action_type_id: TypeId,
+    /// Type name of the `Action` for diagnostics.
+    action_type_name: &'static str,
+    /// Target `Entity` of the `Action`.
+    target_id: Entity,
+    /// [`TypeId`] of the type that the `Action` writes to.
+    target_type_id: TypeId,
+    /// Type name of the type that the `Action` writes to.
+    target_type_name: &'static str,
+    /// Path of the field that the `Action` writes to.
+    field_path: Option<&'static str>,
+    /// Whether the `Action` writes as an additive layer.
+    additive: bool,
     /// Time at which animation should begin.
     pub(crate) start_time: f32,
     /// Duration of animation in seconds.
     pub(crate) duration: f32,
+    /// Mapping from the sequence time onto the unit time of the action.
+    clip: TimeClip,
+    /// Time warps of eased composites that the action is nested in, applied after the `clip`.
+    warp: Option<Arc<TimeWarp>>,
     /// Slide that this action belongs to.
     pub(crate) slide_index: usize,
 }
 
 impl ActionMeta {
-    pub fn new(action_id: Entity) -> Self {
+    pub fn new<T, U>(action_id: Entity, action: &Action<T, U>) -> Self
+    where
+        T: 'static,
+        U: 'static,
+    {
         Self {
             action_id,
+            action_type_id: TypeId::of::<Action<T, U>>(),
+            action_type_name: std::any::type_name::<Action<T, U>>(),
+            target_id: action.target_id,
+            target_type_id: TypeId::of::<U>(),
+            target_type_name: std::any::type_name::<U>(),
+            field_path: action.field_path,
+            additive: matches!(action.layer, Layer::Additive { .. }),
             start_time: 0.0,
             duration: 0.0,
+            clip: TimeClip::new(0.0, 0.0),
+            warp: None,
             slide_index: 0,
         }
     }
@@ -226,9 +669,38 @@ impl ActionMeta {
         self.action_id
     }
 
+    pub fn action_type_id(&self) -> TypeId {
+        self.action_type_id
+    }
+
+    pub fn action_type_name(&self) -> &'static str {
+        self.action_type_name
+    }
+
+    pub fn target_id(&self) -> Entity {
+        self.target_id
+    }
+
+    pub fn target_type_id(&self) -> TypeId {
+        self.target_type_id
+    }
+
+    pub fn target_type_name(&self) -> &'static str {
+        self.target_type_name
+    }
+
+    pub fn field_path(&self) -> Option<&'static str> {
+        self.field_path
+    }
+
+    pub fn is_additive(&self) -> bool {
+        self.additive
+    }
+
     #[inline]
-    pub fn with_start_time(mut self, start_time: f32) -> Self {
-        self.start_time = start_time;
+    pub fn with_duration(mut self, duration: f32) -> Self {
+        self.duration = duration;
+        self.clip = TimeClip::new(self.start_time, duration);
         self
     }
 
@@ -236,9 +708,87 @@ impl ActionMeta {
     pub fn end_time(&self) -> f32 {
         self.start_time + self.duration
     }
+
+    /// Calculate the (un-eased) unit time of the action at a given `time`, clamped between 0.0 and 1.0.
+    #[inline]
+    pub fn unit_time(&self, time: f32) -> f32 {
+        let mut unit_time = self.clip.unit_time(time);
+
+        let mut warp = self.warp.as_deref();
+        while let Some(time_warp) = warp {
+            unit_time = time_warp.clip.unit_time(time_warp.local_time(unit_time));
+            warp = time_warp.inner.as_deref();
+        }
+
+        unit_time
+    }
+
+    /// Shift the action forward in time.
+    pub fn shifted(&self, offset: f32) -> Self {
+        Self {
+            start_time: self.start_time + offset,
+            clip: self.clip.shifted(offset),
+            ..self.clone()
+        }
+    }
+
+    /// Scale the timing of the action.
+    pub fn stretched(&self, scale: f32) -> Self {
+        Self {
+            start_time: self.start_time * scale,
+            duration: self.duration * scale,
+            clip: self.clip.stretched(scale),
+            ..self.clone()
+        }
+    }
+
+    /// Play the action backwards within a sequence of `duration`.
+    pub fn reversed(&self, duration: f32) -> Self {
+        Self {
+            start_time: duration - self.end_time(),
+            clip: self.clip.reversed(duration),
+            ..self.clone()
+        }
+    }
+
+    /// Cut the action to the time range `[start, end]` and shift it back by `start`.
+    pub fn trimmed(&self, start: f32, end: f32) -> Self {
+        let start_time = f32::clamp(self.start_time, start, end);
+        let end_time = f32::clamp(self.end_time(), start, end);
+
+        Self {
+            start_time: start_time - start,
+            duration: end_time - start_time,
+            clip: self.clip.trimmed(start, end),
+            ..self.clone()
+        }
+    }
+
+    /// Ease the time of the action as part of a composite of `duration`.
+    pub fn eased(&self, ease_fn: &Ease, duration: f32) -> Self {
+        let (start_time, end_time) = (self.start_time, self.end_time());
+
+        // Outer time range in which the action changes.
+        let outer_start = first_time(ease_fn, duration, |time| time > start_time);
+        let outer_end = last_time(ease_fn, duration, |time| time < end_time);
+        let outer_end = f32::max(outer_start, outer_end);
+
+        Self {
+            start_time: outer_start,
+            duration: outer_end - outer_start,
+            clip: TimeClip::new(0.0, duration),
+            warp: Some(Arc::new(TimeWarp {
+                ease_fn: ease_fn.clone(),
+                duration,
+                clip: self.clip,
+                inner: self.warp.clone(),
+            })),
+            ..self.clone()
+        }
+    }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub struct Motion<T, U> {
     pub action: Action<T, U>,
     pub duration: f32,
@@ -281,6 +831,10 @@ impl MultiSeqOrd for SequenceBuilder<'_, '_> {
     fn flow(self, delay: f32) -> Sequence {
         self.sequences.flow(delay)
     }
+
+    fn stagger(self, stagger: &Stagger) -> Sequence {
+        self.sequences.stagger(stagger)
+    }
 }
 
 pub trait SequenceBuilderExt<'w> {
@@ -305,9 +859,9 @@ impl<'w> SequenceBuilderExt<'w> for Commands<'w, '_> {
         T: Send + Sync + 'static,
         U: Send + Sync + 'static,
     {
-        let action_id = self.spawn(motion.action).id();
-        let mut action_meta = ActionMeta::new(action_id);
-        action_meta.duration = motion.duration;
+        let action_id = self.spawn_empty().id();
+        let action_meta = ActionMeta::new(action_id, &motion.action).with_duration(motion.duration);
+        self.entity(action_id).insert(motion.action);
 
         Sequence::single(action_meta)
     }
@@ -0,0 +1,151 @@
+use bevy::{
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+
+use crate::sequence::SequenceController;
+
+/// Drives the [`SequenceController`] on this entity from the [`SequenceController`] of a `master` entity.
+///
+/// The linked time is `(master_time - offset) * time_scale`, so scrubbing, pausing or
+/// [seeking](SequenceController::seek) the master carries over to every linked controller.
+/// Masters can be linked to other masters, and linked controllers should not have a
+/// [`SequencePlayer`](crate::sequence::SequencePlayer) of their own.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::{prelude::*, MotionGfxCorePlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, MotionGfxCorePlugin));
+///
+/// let world = app.world_mut();
+/// let master = world
+///     .spawn(SequenceBundle::from_sequence(Sequence::default().delay(10.0)))
+///     .id();
+/// let background = world
+///     .spawn((
+///         SequenceBundle::from_sequence(Sequence::default().delay(10.0)),
+///         ClockLink::new(master).with_time_scale(0.5),
+///     ))
+///     .id();
+/// let story = world
+///     .spawn((
+///         SequenceBundle::from_sequence(Sequence::default().delay(10.0)),
+///         ClockLink::new(master).with_offset(1.0).with_time_scale(2.0),
+///     ))
+///     .id();
+///
+/// for (time, background_time, story_time) in [(3.0, 1.5, 4.0), (0.5, 0.25, 0.0)] {
+///     app.world_mut()
+///         .get_mut::<SequenceController>(master)
+///         .unwrap()
+///         .seek(time);
+///     app.update();
+///
+///     let target_time = |id: Entity| app.world().get::<SequenceController>(id).unwrap().target_time;
+///     assert_eq!(target_time(background), background_time);
+///     assert_eq!(target_time(story), story_time);
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ClockLink {
+    /// Entity with the master [`SequenceController`].
+    pub master: Entity,
+    /// Master time at which the linked time starts.
+    pub offset: f32,
+    /// Speed of the linked time relative to the master time.
+    pub time_scale: f32,
+}
+
+impl ClockLink {
+    pub fn new(master: Entity) -> Self {
+        Self {
+            master,
+            offset: 0.0,
+            time_scale: 1.0,
+        }
+    }
+
+    pub fn with_offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn with_time_scale(mut self, time_scale: f32) -> Self {
+        self.time_scale = time_scale;
+        self
+    }
+
+    /// Linked time at a given master `time`.
+    #[inline]
+    pub fn linked_time(&self, time: f32) -> f32 {
+        (time - self.offset) * self.time_scale
+    }
+}
+
+/// Maximum number of [`ClockLink`]s between a controller and its root master.
+const MAX_LINK_DEPTH: usize = 64;
+
+/// Update the [`SequenceController`]s of every [`ClockLink`] from their masters.
+pub(crate) fn sync_clock_links(
+    q_links: Query<(Entity, &ClockLink)>,
+    mut q_controllers: Query<&mut SequenceController>,
+    mut removed_links: RemovedComponents<ClockLink>,
+    mut linked_times: Local<HashMap<Entity, (f32, bool)>>,
+    // Links that have been warned about while they stay cyclic or too deep.
+    mut reported: Local<HashSet<Entity>>,
+) {
+    // Forget links that are gone, as their entities can be reused.
+    for id in removed_links.read() {
+        reported.remove(&id);
+    }
+
+    for (id, clock_link) in q_links.iter() {
+        // Walk up to the root master.
+        let mut links = vec![clock_link];
+        let mut master = clock_link.master;
+        while let Ok((_, clock_link)) = q_links.get(master) {
+            if links.len() >= MAX_LINK_DEPTH {
+                break;
+            }
+
+            links.push(clock_link);
+            master = clock_link.master;
+        }
+
+        if links.len() >= MAX_LINK_DEPTH {
+            if reported.insert(id) {
+                warn!("Clock link of {id} is cyclic or too deep.");
+            }
+            continue;
+        }
+        reported.remove(&id);
+
+        let Ok(master_controller) = q_controllers.get(master) else {
+            continue;
+        };
+
+        let time = links
+            .iter()
+            .rev()
+            .fold(master_controller.target_time, |time, clock_link| {
+                clock_link.linked_time(time)
+            });
+
+        linked_times.insert(id, (time, master_controller.resample));
+    }
+
+    for (id, (time, resample)) in linked_times.drain() {
+        let Ok(mut sequence_controller) = q_controllers.get_mut(id) else {
+            continue;
+        };
+
+        match resample {
+            true => sequence_controller.seek(time),
+            false => sequence_controller.target_time = time,
+        }
+    }
+}
@@ -0,0 +1,350 @@
+//! Detection of [`Action`]s that write to the same field over overlapping time ranges.
+//!
+//! Overlapping actions on the same field overwrite each other in evaluation order,
+//! unless all of them are [additive](crate::action::Layer::Additive).
+//! Only actions with a [field path](crate::action::Action::with_field_path) are checked.
+//!
+//! [`Action`]: crate::action::Action
+
+use std::{any::TypeId, borrow::Cow, fmt, ops::Range};
+
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::{
+    action::ActionMeta,
+    sequence::{Sequence, SequenceBundle, SequencePlayerBundle},
+};
+
+/// How conflicting actions are reported when a [`Sequence`] is added to the world.
+///
+/// Added [`Sequence`]s are checked against themselves and every [`Sequence`] already in the world,
+/// assuming that all of them are played in sync.
+/// Actions inside [`SequenceClip`](crate::sequence::SequenceClip)s are included.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictPolicy {
+    /// Do not check for conflicts.
+    Ignore,
+    /// Log a warning for every conflict.
+    #[default]
+    Warn,
+    /// Log an error and send a [`ConflictEvent`] for every [`Sequence`] with conflicts,
+    /// useful for catching conflicts in CI.
+    Strict,
+}
+
+/// Sent under [`ConflictPolicy::Strict`] when an added [`Sequence`] contains actions
+/// that conflict with each other or with another [`Sequence`].
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::{conflict::ConflictEvent, prelude::*, MotionGfxCorePlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, MotionGfxCorePlugin))
+///     .insert_resource(ConflictPolicy::Strict);
+///
+/// let mut transform = Transform::default();
+/// let id = app.world_mut().spawn(transform).id();
+///
+/// let mut commands = app.world_mut().commands();
+/// let sequence = [
+///     commands.play_motion(
+///         act!((id, Transform), start = { transform }.translation.x, end = 1.0,).animate(1.0),
+///     ),
+///     commands.play_motion(
+///         act!((id, Transform), start = { transform }.translation.x, end = 2.0,).animate(1.0),
+///     ),
+/// ]
+/// .all();
+/// let sequence_id = commands.spawn(SequenceBundle::from_sequence(sequence)).id();
+/// app.world_mut().flush();
+/// app.update();
+///
+/// let events = app.world().resource::<Events<ConflictEvent>>();
+/// let mut reader = events.get_reader();
+/// let events = reader.read(events).collect::<Vec<_>>();
+/// assert_eq!(events.len(), 1);
+/// assert_eq!(events[0].sequence_id, sequence_id);
+/// assert_eq!(events[0].error.0.len(), 1);
+///
+/// // Sequences that are added later are checked against the ones that are already playing.
+/// let mut commands = app.world_mut().commands();
+/// let sequence = commands.play_motion(
+///     act!((id, Transform), start = { transform }.translation.x, end = 3.0,).animate(1.0),
+/// );
+/// let other_id = commands.spawn(SequenceBundle::from_sequence(sequence)).id();
+/// app.world_mut().flush();
+/// app.update();
+///
+/// let events = app.world().resource::<Events<ConflictEvent>>();
+/// let events = reader.read(events).collect::<Vec<_>>();
+/// assert_eq!(events.len(), 1);
+/// assert_eq!(events[0].sequence_id, other_id);
+/// assert_eq!(events[0].error.0.len(), 2);
+/// ```
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct ConflictEvent {
+    /// The [`Sequence`] entity.
+    pub sequence_id: Entity,
+    /// Every conflict inside the [`Sequence`].
+    pub error: ConflictError,
+}
+
+/// 2 actions that write to the same field over overlapping time ranges.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActionConflict {
+    /// Target [`Entity`] of both actions.
+    pub target_id: Entity,
+    /// Type name of the component (or asset/resource) that both actions write to.
+    pub target_type_name: &'static str,
+    /// [`Entity`] of both actions.
+    pub action_ids: [Entity; 2],
+    /// Field paths of both actions.
+    pub field_paths: [&'static str; 2],
+    /// Time ranges of both actions.
+    pub time_ranges: [Range<f32>; 2],
+}
+
+impl fmt::Display for ActionConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let field = |index: usize| match self.field_paths[index] {
+            "" => self.target_type_name.to_string(),
+            path => format!("{}.{}", self.target_type_name, path),
+        };
+
+        write!(
+            f,
+            "`{}` ({:?}..{:?}) and `{}` ({:?}..{:?}) on {} are written by overlapping actions.",
+            field(0),
+            self.time_ranges[0].start,
+            self.time_ranges[0].end,
+            field(1),
+            self.time_ranges[1].start,
+            self.time_ranges[1].end,
+            self.target_id,
+        )
+    }
+}
+
+/// Error containing every [`ActionConflict`] found.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConflictError(pub Vec<ActionConflict>);
+
+impl fmt::Display for ConflictError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Found {} conflicting action(s):", self.0.len())?;
+        for conflict in &self.0 {
+            write!(f, "\n- {conflict}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConflictError {}
+
+impl Sequence {
+    /// Find every [`ActionConflict`] inside this [`Sequence`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use motiongfx_core::prelude::*;
+    ///
+    /// let mut world = World::new();
+    /// let mut transform = Transform::default();
+    /// let id = world.spawn(transform).id();
+    ///
+    /// let mut commands = world.commands();
+    /// let sequence = [
+    ///     commands.play_motion(
+    ///         act!((id, Transform), start = { transform }.translation.x, end = 1.0,).animate(1.0),
+    ///     ),
+    ///     commands.play_motion(
+    ///         act!((id, Transform), start = { transform }.translation, end = Vec3::ONE,).animate(1.0),
+    ///     ),
+    /// ]
+    /// .all();
+    ///
+    /// assert_eq!(sequence.conflicts().len(), 1);
+    /// assert!(SequenceBundle::try_from_sequence(sequence).is_err());
+    /// ```
+    pub fn conflicts(&self) -> Vec<ActionConflict> {
+        find_conflicts([self])
+    }
+}
+
+impl SequenceBundle {
+    /// Creates a [`SequenceBundle`], failing if the [`Sequence`] contains any [`ActionConflict`].
+    pub fn try_from_sequence(sequence: Sequence) -> Result<Self, ConflictError> {
+        validate(&sequence)?;
+        Ok(Self::from_sequence(sequence))
+    }
+}
+
+impl SequencePlayerBundle {
+    /// Creates a [`SequencePlayerBundle`], failing if the [`Sequence`] contains any [`ActionConflict`].
+    pub fn try_from_sequence(sequence: Sequence) -> Result<Self, ConflictError> {
+        validate(&sequence)?;
+        Ok(Self::from_sequence(sequence))
+    }
+}
+
+fn validate(sequence: &Sequence) -> Result<(), ConflictError> {
+    let conflicts = sequence.conflicts();
+    match conflicts.is_empty() {
+        true => Ok(()),
+        false => Err(ConflictError(conflicts)),
+    }
+}
+
+/// Find every [`ActionConflict`] across multiple [`Sequence`]s,
+/// assuming that all of them are played in sync.
+pub fn find_conflicts<'a>(
+    sequences: impl IntoIterator<Item = &'a Sequence>,
+) -> Vec<ActionConflict> {
+    // Bake the clips so that every action has its time range inside its sequence.
+    let sequences = sequences
+        .into_iter()
+        .map(|sequence| match sequence.clips().next() {
+            Some(_) => Cow::Owned(sequence.clone().baked()),
+            None => Cow::Borrowed(sequence),
+        })
+        .collect::<Vec<_>>();
+
+    // Group actions by their target.
+    let mut groups = HashMap::<(Entity, TypeId), Vec<&ActionMeta>>::new();
+    for sequence in &sequences {
+        for action_meta in &sequence.action_metas {
+            if action_meta.field_path().is_none() {
+                continue;
+            }
+
+            groups
+                .entry((action_meta.target_id(), action_meta.target_type_id()))
+                .or_default()
+                .push(action_meta);
+        }
+    }
+
+    let mut conflicts = Vec::new();
+    for action_metas in groups.values_mut() {
+        action_metas.sort_by(|a, b| f32::total_cmp(&a.start_time, &b.start_time));
+
+        // Sweep through the actions that start before the current one ends.
+        for (i, a) in action_metas.iter().enumerate() {
+            for b in action_metas[i + 1..]
+                .iter()
+                .take_while(|b| b.start_time <= a.end_time())
+            {
+                if is_conflict(a, b) {
+                    conflicts.push(ActionConflict {
+                        target_id: a.target_id(),
+                        target_type_name: a.target_type_name(),
+                        action_ids: [a.id(), b.id()],
+                        field_paths: [a.field_path().unwrap(), b.field_path().unwrap()],
+                        time_ranges: [a.start_time..a.end_time(), b.start_time..b.end_time()],
+                    });
+                }
+            }
+        }
+    }
+
+    conflicts
+}
+
+/// Check if 2 actions (sorted by start time) on the same target conflict with each other.
+fn is_conflict(a: &ActionMeta, b: &ActionMeta) -> bool {
+    if a.is_additive() && b.is_additive() {
+        return false;
+    }
+
+    // Actions that touch at their ends do not overlap, unless one of them is instant.
+    if b.start_time == a.end_time() && a.duration > 0.0 && b.duration > 0.0 {
+        return false;
+    }
+
+    let (Some(a_path), Some(b_path)) = (a.field_path(), b.field_path()) else {
+        return false;
+    };
+
+    is_path_overlap(a_path, b_path)
+}
+
+/// Check if 2 optional field paths may refer to the same field, unknown paths overlap with any field.
+pub(crate) fn is_field_overlap(a: Option<&str>, b: Option<&str>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => is_path_overlap(a, b),
+        _ => true,
+    }
+}
+
+/// Check if 2 field paths refer to the same field or if one is nested inside the other.
+fn is_path_overlap(a: &str, b: &str) -> bool {
+    let (short, long) = match a.len() <= b.len() {
+        true => (a, b),
+        false => (b, a),
+    };
+
+    short.is_empty()
+        || (long.starts_with(short)
+            && (long.len() == short.len() || matches!(long.as_bytes()[short.len()], b'.' | b'[')))
+}
+
+/// Report conflicts of newly added [`Sequence`]s based on the [`ConflictPolicy`].
+pub(crate) fn detect_conflicts(
+    q_sequences: Query<(Entity, Ref<Sequence>)>,
+    policy: Res<ConflictPolicy>,
+    mut evw_conflict: EventWriter<ConflictEvent>,
+) {
+    if *policy == ConflictPolicy::Ignore {
+        return;
+    }
+
+    // Owner of every action inside the added sequences.
+    let mut added_ids = HashMap::new();
+    for (sequence_id, sequence) in q_sequences.iter() {
+        if sequence.is_added() {
+            sequence.for_each_action_meta(&mut |action_meta| {
+                added_ids.insert(action_meta.id(), sequence_id);
+            });
+        }
+    }
+    if added_ids.is_empty() {
+        return;
+    }
+
+    // Only keep conflicts that involve an added sequence.
+    let mut errors = HashMap::<Entity, Vec<ActionConflict>>::new();
+    for conflict in find_conflicts(
+        q_sequences
+            .iter()
+            .map(|(_, sequence)| sequence.into_inner()),
+    ) {
+        let sequence_id = conflict
+            .action_ids
+            .iter()
+            .find_map(|action_id| added_ids.get(action_id));
+
+        if let Some(&sequence_id) = sequence_id {
+            errors.entry(sequence_id).or_default().push(conflict);
+        }
+    }
+
+    for (sequence_id, conflicts) in errors {
+        match *policy {
+            ConflictPolicy::Strict => {
+                let error = ConflictError(conflicts);
+                error!("{error}");
+                evw_conflict.send(ConflictEvent { sequence_id, error });
+            }
+            _ => {
+                for conflict in &conflicts {
+                    warn!("{conflict}");
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,113 @@
-use std::f32::consts::PI;
+use std::{any::Any, f32::consts::PI, sync::Arc};
 
 pub type EaseFn = fn(t: f32) -> f32;
+/// Shared closure version of [`EaseFn`].
+pub type EaseClosure = Arc<dyn Fn(f32) -> f32 + Send + Sync>;
+
+/// An easing function that is either a plain [`EaseFn`] or a shared closure that can capture state.
+///
+/// # Example
+///
+/// Every easing function and combinator starts at exactly 0.0 and ends at exactly 1.0,
+/// so that actions land on their start and end values.
+///
+/// ```rust
+/// use motiongfx_core::{
+///     ease::{Ease, EaseFn, StepJump},
+///     prelude::*,
+/// };
+///
+/// let families: [[EaseFn; 3]; 11] = [
+///     [ease::sine::ease_in, ease::sine::ease_out, ease::sine::ease_in_out],
+///     [ease::quad::ease_in, ease::quad::ease_out, ease::quad::ease_in_out],
+///     [ease::cubic::ease_in, ease::cubic::ease_out, ease::cubic::ease_in_out],
+///     [ease::quart::ease_in, ease::quart::ease_out, ease::quart::ease_in_out],
+///     [ease::quint::ease_in, ease::quint::ease_out, ease::quint::ease_in_out],
+///     [ease::expo::ease_in, ease::expo::ease_out, ease::expo::ease_in_out],
+///     [ease::circ::ease_in, ease::circ::ease_out, ease::circ::ease_in_out],
+///     [ease::back::ease_in, ease::back::ease_out, ease::back::ease_in_out],
+///     [ease::elastic::ease_in, ease::elastic::ease_out, ease::elastic::ease_in_out],
+///     [ease::bounce::ease_in, ease::bounce::ease_out, ease::bounce::ease_in_out],
+///     [ease::linear, ease::linear, ease::linear],
+/// ];
+/// let ease_fns = families.into_iter().flatten().collect::<Vec<_>>();
+///
+/// let mut eases = Vec::new();
+/// for &a in &ease_fns {
+///     eases.push(Ease::from_fn(a));
+///     eases.push(Ease::new(move |t| a(t)));
+///     eases.push(Ease::new(ease::reverse(a)));
+///     eases.push(Ease::new(ease::mirror(a)));
+///
+///     for &b in &ease_fns {
+///         eases.push(Ease::new(ease::chain(a, b)));
+///         eases.push(Ease::new(ease::blend(a, b, 0.3)));
+///     }
+/// }
+///
+/// for n in 0..5 {
+///     for jump in [StepJump::Start, StepJump::End, StepJump::None, StepJump::Both] {
+///         eases.push(Ease::new(ease::steps(n, jump)));
+///     }
+/// }
+///
+/// for (x1, y1, x2, y2) in [(0.25, 0.1, 0.25, 1.0), (0.42, 0.0, 0.58, 1.0), (0.7, -0.5, 0.3, 1.5)] {
+///     eases.push(Ease::new(ease::cubic_bezier(x1, y1, x2, y2)));
+/// }
+///
+/// // Underdamped, critically damped, overdamped and barely damped springs.
+/// for damping in [10.0, 20.0, 40.0, 0.01] {
+///     eases.push(Ease::new(ease::spring(100.0, damping, 1.0).0));
+/// }
+///
+/// for ease in &eases {
+///     assert_eq!(ease.ease(0.0), 0.0);
+///     assert_eq!(ease.ease(1.0), 1.0);
+/// }
+/// ```
+#[derive(Clone)]
+pub enum Ease {
+    /// Plain function pointer (fast path).
+    Fn(EaseFn),
+    /// Shared closure.
+    Closure(EaseClosure),
+}
+
+impl Ease {
+    /// Creates a new [`Ease`] from a plain [`EaseFn`] (fast path).
+    pub const fn from_fn(ease_fn: EaseFn) -> Self {
+        Self::Fn(ease_fn)
+    }
+
+    /// Creates a new [`Ease`] from any easing function.
+    ///
+    /// Only values that are already an [`EaseFn`] take the fast path, function items
+    /// (e.g. `ease::quad::ease_in`) are boxed as closures. Use [`Ease::from_fn`] for those.
+    pub fn new<F>(ease_fn: F) -> Self
+    where
+        F: Fn(f32) -> f32 + Send + Sync + 'static,
+    {
+        match (&ease_fn as &dyn Any).downcast_ref::<EaseFn>() {
+            Some(ease_fn) => Self::Fn(*ease_fn),
+            None => Self::Closure(Arc::new(ease_fn)),
+        }
+    }
+
+    /// Ease the [`f32`] time value.
+    #[inline]
+    pub fn ease(&self, t: f32) -> f32 {
+        match self {
+            Self::Fn(ease_fn) => ease_fn(t),
+            Self::Closure(ease_fn) => ease_fn(t),
+        }
+    }
+}
+
+impl From<EaseFn> for Ease {
+    fn from(ease_fn: EaseFn) -> Self {
+        Self::Fn(ease_fn)
+    }
+}
 
 pub fn linear(t: f32) -> f32 {
     t
@@ -24,7 +131,7 @@ pub mod sine {
 
     #[inline]
     pub fn ease_out(t: f32) -> f32 {
-        f32::cos(t * PI * 0.5)
+        f32::sin(t * PI * 0.5)
     }
 
     #[inline]
@@ -251,3 +358,415 @@ pub mod elastic {
         }
     }
 }
+
+/// # Example
+///
+/// ```rust
+/// use motiongfx_core::prelude::*;
+///
+/// for ease_fn in [ease::bounce::ease_in, ease::bounce::ease_out, ease::bounce::ease_in_out] {
+///     assert_eq!(ease_fn(0.0), 0.0);
+///     assert_eq!(ease_fn(1.0), 1.0);
+/// }
+/// ```
+pub mod bounce {
+    const N1: f32 = 7.5625;
+    const D1: f32 = 2.75;
+
+    #[inline]
+    pub fn ease_in(t: f32) -> f32 {
+        1.0 - ease_out(1.0 - t)
+    }
+
+    #[inline]
+    pub fn ease_out(t: f32) -> f32 {
+        if t == 1.0 {
+            1.0
+        } else if t < 1.0 / D1 {
+            N1 * t * t
+        } else if t < 2.0 / D1 {
+            let t = t - 1.5 / D1;
+            N1 * t * t + 0.75
+        } else if t < 2.5 / D1 {
+            let t = t - 2.25 / D1;
+            N1 * t * t + 0.9375
+        } else {
+            let t = t - 2.625 / D1;
+            N1 * t * t + 0.984375
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_out(t: f32) -> f32 {
+        if t < 0.5 {
+            (1.0 - ease_out(1.0 - 2.0 * t)) * 0.5
+        } else {
+            (1.0 + ease_out(2.0 * t - 1.0)) * 0.5
+        }
+    }
+}
+
+/// Creates a CSS-style `cubic-bezier(x1, y1, x2, y2)` easing function.
+///
+/// The curve starts at `(0, 0)` and ends at `(1, 1)` with `(x1, y1)` and `(x2, y2)` as the control points.
+/// `x1` and `x2` are clamped between 0.0 and 1.0 so that the curve stays a function of time.
+///
+/// # Example
+///
+/// ```rust
+/// use motiongfx_core::prelude::*;
+///
+/// // CSS `ease`
+/// let ease_fn = ease::cubic_bezier(0.25, 0.1, 0.25, 1.0);
+///
+/// assert_eq!(ease_fn(0.0), 0.0);
+/// assert_eq!(ease_fn(1.0), 1.0);
+/// assert!((ease_fn(0.5) - 0.8024).abs() < 1e-3);
+/// ```
+pub fn cubic_bezier(
+    x1: f32,
+    y1: f32,
+    x2: f32,
+    y2: f32,
+) -> impl Fn(f32) -> f32 + Copy + Send + Sync + 'static {
+    const NEWTON_ITERATIONS: usize = 8;
+    const BISECTION_ITERATIONS: usize = 32;
+    const EPSILON: f32 = 1e-6;
+
+    let x1 = f32::clamp(x1, 0.0, 1.0);
+    let x2 = f32::clamp(x2, 0.0, 1.0);
+
+    // Polynomial coefficients of the curve.
+    let cx = 3.0 * x1;
+    let bx = 3.0 * (x2 - x1) - cx;
+    let ax = 1.0 - cx - bx;
+
+    let cy = 3.0 * y1;
+    let by = 3.0 * (y2 - y1) - cy;
+    let ay = 1.0 - cy - by;
+
+    let sample_x = move |s: f32| ((ax * s + bx) * s + cx) * s;
+    let sample_y = move |s: f32| ((ay * s + by) * s + cy) * s;
+    let sample_dx = move |s: f32| (3.0 * ax * s + 2.0 * bx) * s + cx;
+
+    // Find the curve parameter `s` where `x(s) == t`.
+    let solve_x = move |t: f32| {
+        // Newton's method converges fast for most curves.
+        let mut s = t;
+        for _ in 0..NEWTON_ITERATIONS {
+            let x = sample_x(s) - t;
+            if f32::abs(x) < EPSILON {
+                return s;
+            }
+
+            let dx = sample_dx(s);
+            if f32::abs(dx) < EPSILON {
+                break;
+            }
+            s -= x / dx;
+        }
+
+        // Fallback to bisection.
+        let (mut lo, mut hi) = (0.0, 1.0);
+        s = t;
+        for _ in 0..BISECTION_ITERATIONS {
+            let x = sample_x(s);
+            if f32::abs(x - t) < EPSILON {
+                break;
+            }
+
+            if x < t {
+                lo = s;
+            } else {
+                hi = s;
+            }
+            s = (lo + hi) * 0.5;
+        }
+
+        s
+    };
+
+    move |t| {
+        if t <= 0.0 {
+            0.0
+        } else if t >= 1.0 {
+            1.0
+        } else {
+            sample_y(solve_x(t))
+        }
+    }
+}
+
+/// Where the jumps of [`steps`] happen, following the CSS `<step-position>`.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StepJump {
+    /// The first jump happens right after the start.
+    Start,
+    /// The last jump happens at the end.
+    #[default]
+    End,
+    /// No jump at the start or the end, holding both the start and end values.
+    None,
+    /// Jumps happen right after the start and at the end.
+    Both,
+}
+
+/// Creates a CSS-style `steps(n, jump)` easing function.
+///
+/// Unlike CSS, the start and end values are always exactly 0.0 and 1.0,
+/// so that an action can be restored to its start value when played backwards.
+///
+/// # Example
+///
+/// ```rust
+/// use motiongfx_core::prelude::*;
+///
+/// let ease_fn = ease::steps(4, ease::StepJump::End);
+///
+/// assert_eq!(ease_fn(0.0), 0.0);
+/// assert_eq!(ease_fn(0.3), 0.25);
+/// assert_eq!(ease_fn(0.99), 0.75);
+/// assert_eq!(ease_fn(1.0), 1.0);
+///
+/// let ease_fn = ease::steps(4, ease::StepJump::Start);
+///
+/// assert_eq!(ease_fn(0.0), 0.0);
+/// assert_eq!(ease_fn(0.01), 0.25);
+/// assert_eq!(ease_fn(1.0), 1.0);
+/// ```
+pub fn steps(n: usize, jump: StepJump) -> impl Fn(f32) -> f32 + Copy + Send + Sync + 'static {
+    let n = usize::max(n, 1) as f32;
+    let intervals = match jump {
+        StepJump::Start | StepJump::End => n,
+        StepJump::None => f32::max(n - 1.0, 1.0),
+        StepJump::Both => n + 1.0,
+    };
+
+    move |t| {
+        if t <= 0.0 {
+            return 0.0;
+        } else if t >= 1.0 {
+            return 1.0;
+        }
+
+        let mut step = f32::floor(t * n);
+        if matches!(jump, StepJump::Start | StepJump::Both) {
+            step += 1.0;
+        }
+
+        f32::clamp(step / intervals, 0.0, 1.0)
+    }
+}
+
+/// Plays an easing function backwards, e.g. turning an ease in into an ease out.
+///
+/// # Example
+///
+/// ```rust
+/// use motiongfx_core::prelude::*;
+///
+/// let ease_fn = ease::reverse(ease::quad::ease_in);
+///
+/// assert_eq!(ease_fn(0.0), 0.0);
+/// assert_eq!(ease_fn(0.25), ease::quad::ease_out(0.25));
+/// assert_eq!(ease_fn(1.0), 1.0);
+/// ```
+pub fn reverse<E>(ease_fn: E) -> impl Fn(f32) -> f32 + Clone + Send + Sync + 'static
+where
+    E: Fn(f32) -> f32 + Clone + Send + Sync + 'static,
+{
+    move |t| 1.0 - ease_fn(1.0 - t)
+}
+
+/// Plays an easing function in the first half and its [reverse] in the second half,
+/// e.g. turning an ease in into an ease in out.
+///
+/// # Example
+///
+/// ```rust
+/// use motiongfx_core::prelude::*;
+///
+/// let ease_fn = ease::mirror(ease::quad::ease_in);
+///
+/// assert_eq!(ease_fn(0.0), 0.0);
+/// assert_eq!(ease_fn(0.5), 0.5);
+/// assert_eq!(ease_fn(0.25), ease::quad::ease_in_out(0.25));
+/// assert_eq!(ease_fn(1.0), 1.0);
+/// ```
+pub fn mirror<E>(ease_fn: E) -> impl Fn(f32) -> f32 + Clone + Send + Sync + 'static
+where
+    E: Fn(f32) -> f32 + Clone + Send + Sync + 'static,
+{
+    chain(ease_fn.clone(), reverse(ease_fn))
+}
+
+/// Plays the `first` easing function in the first half and the `second` easing function in the second half.
+///
+/// # Example
+///
+/// ```rust
+/// use motiongfx_core::prelude::*;
+///
+/// let ease_fn = ease::chain(ease::bounce::ease_in, ease::elastic::ease_out);
+///
+/// assert_eq!(ease_fn(0.0), 0.0);
+/// assert_eq!(ease_fn(0.5), 0.5);
+/// assert_eq!(ease_fn(1.0), 1.0);
+/// ```
+pub fn chain<E0, E1>(first: E0, second: E1) -> impl Fn(f32) -> f32 + Clone + Send + Sync + 'static
+where
+    E0: Fn(f32) -> f32 + Clone + Send + Sync + 'static,
+    E1: Fn(f32) -> f32 + Clone + Send + Sync + 'static,
+{
+    move |t| {
+        if t < 0.5 {
+            first(t * 2.0) * 0.5
+        } else {
+            second(t * 2.0 - 1.0) * 0.5 + 0.5
+        }
+    }
+}
+
+/// Blends between 2 easing functions using a `weight` from 0.0 (only `a`) to 1.0 (only `b`).
+///
+/// # Example
+///
+/// ```rust
+/// use motiongfx_core::prelude::*;
+///
+/// let ease_fn = ease::blend(ease::linear, ease::back::ease_out, 0.3);
+///
+/// assert_eq!(ease_fn(0.0), 0.0);
+/// assert_eq!(ease_fn(1.0), 1.0);
+/// ```
+pub fn blend<E0, E1>(
+    a: E0,
+    b: E1,
+    weight: f32,
+) -> impl Fn(f32) -> f32 + Clone + Send + Sync + 'static
+where
+    E0: Fn(f32) -> f32 + Clone + Send + Sync + 'static,
+    E1: Fn(f32) -> f32 + Clone + Send + Sync + 'static,
+{
+    move |t| {
+        let a = a(t);
+        a + (b(t) - a) * weight
+    }
+}
+
+/// Creates a damped spring easing function along with the duration (in seconds) it takes for the spring to settle.
+///
+/// The spring starts at rest and is released towards the end value, overshooting it when underdamped
+/// (`damping < 2.0 * f32::sqrt(stiffness * mass)`). The easing function is based on the closed form
+/// solution of the spring, so it can be sampled at any time.
+///
+/// Springs that take longer than 60 seconds to settle are cut at 60 seconds,
+/// with the remaining distance spread over the whole duration so that the easing function still ends at 1.0.
+///
+/// # Panics
+///
+/// Panics if `damping` is not positive, as an undamped spring never settles.
+///
+/// # Example
+///
+/// ```rust
+/// use motiongfx_core::prelude::*;
+///
+/// let (ease_fn, duration) = ease::spring(100.0, 10.0, 1.0);
+///
+/// assert!(duration > 0.0);
+/// assert_eq!(ease_fn(0.0), 0.0);
+/// assert_eq!(ease_fn(1.0), 1.0);
+/// // Overshoots the end value.
+/// assert!((0..100).any(|t| ease_fn(t as f32 / 100.0) > 1.0));
+///
+/// // Barely damped springs are cut without jumping to the end value.
+/// let (ease_fn, duration) = ease::spring(100.0, 0.01, 1.0);
+/// assert_eq!(duration, 60.0);
+/// assert!(f32::abs(ease_fn(1.0 - f32::EPSILON) - 1.0) < 1e-3);
+/// ```
+pub fn spring(
+    stiffness: f32,
+    damping: f32,
+    mass: f32,
+) -> (impl Fn(f32) -> f32 + Copy + Send + Sync + 'static, f32) {
+    /// Distance from the end value (relative to the start) at which the spring is considered settled.
+    const SETTLE_THRESHOLD: f32 = 1e-3;
+    /// Upper bound of the duration for springs that barely settle.
+    const MAX_DURATION: f32 = 60.0;
+    /// Damping ratios this close to 1.0 are treated as critically damped,
+    /// as the other solutions lose precision around it.
+    const CRITICAL_EPSILON: f32 = 1e-3;
+
+    assert!(
+        damping > 0.0,
+        "The damping of a spring must be positive, got {damping}."
+    );
+
+    let stiffness = f32::max(stiffness, f32::EPSILON);
+    let mass = f32::max(mass, f32::EPSILON);
+
+    // Natural frequency and damping ratio.
+    let omega = f32::sqrt(stiffness / mass);
+    let zeta = damping / (2.0 * f32::sqrt(stiffness * mass));
+    let is_critical = f32::abs(zeta - 1.0) < CRITICAL_EPSILON;
+    let is_underdamped = zeta < 1.0 && is_critical == false;
+
+    // Displacement from the end value (starting at 1.0) at a given time.
+    let displacement = move |time: f32| {
+        if is_critical {
+            f32::exp(-omega * time) * (1.0 + omega * time)
+        } else if is_underdamped {
+            let omega_d = omega * f32::sqrt(1.0 - zeta * zeta);
+            f32::exp(-zeta * omega * time)
+                * (f32::cos(omega_d * time) + (zeta * omega / omega_d) * f32::sin(omega_d * time))
+        } else {
+            let root = f32::sqrt(zeta * zeta - 1.0);
+            let r0 = -omega * (zeta - root);
+            let r1 = -omega * (zeta + root);
+            (r1 * f32::exp(r0 * time) - r0 * f32::exp(r1 * time)) / (r1 - r0)
+        }
+    };
+
+    let duration = if is_underdamped {
+        // Time at which the decaying envelope falls under the threshold.
+        let amplitude = 1.0 / f32::sqrt(1.0 - zeta * zeta);
+        f32::ln(amplitude / SETTLE_THRESHOLD) / (zeta * omega)
+    } else {
+        // The displacement decreases monotonically, search for the threshold.
+        let mut hi = 1.0 / omega;
+        while displacement(hi) > SETTLE_THRESHOLD && hi < MAX_DURATION {
+            hi *= 2.0;
+        }
+
+        let mut lo = 0.0;
+        for _ in 0..32 {
+            let mid = (lo + hi) * 0.5;
+            if displacement(mid) > SETTLE_THRESHOLD {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        hi
+    };
+    let duration = match duration.is_finite() {
+        true => f32::min(duration, MAX_DURATION),
+        false => MAX_DURATION,
+    };
+
+    // Displacement left at the end, removed linearly over the duration.
+    let residual = displacement(duration);
+
+    let ease_fn = move |t: f32| {
+        if t <= 0.0 {
+            0.0
+        } else if t >= 1.0 {
+            1.0
+        } else {
+            1.0 - (displacement(t * duration) - residual * t)
+        }
+    };
+
+    (ease_fn, duration)
+}
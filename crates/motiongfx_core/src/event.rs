@@ -0,0 +1,193 @@
+use bevy::prelude::*;
+
+use crate::sequence::{generate_action_iter, Sequence, SequenceController};
+
+/// Direction in which the playhead of a [`Sequence`] is moving.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlaybackDirection {
+    Forward,
+    Backward,
+}
+
+/// What happened inside a [`SequenceEvent`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum SequenceEventKind {
+    /// The playhead crossed the start time of an [`Action`](crate::action::Action).
+    ActionStarted(Entity),
+    /// The playhead crossed the end time of an [`Action`](crate::action::Action).
+    ActionFinished(Entity),
+    /// The playhead crossed a named marker.
+    MarkerCrossed(String),
+    /// The playhead reached the end of the [`Sequence`] (or the start when playing backwards).
+    Finished,
+}
+
+/// Fired when the playhead of a [`Sequence`] crosses a point in time.
+///
+/// Each event is sent as a buffered event and also triggered on the [`Sequence`] entity for observers.
+/// The points that are crossed within an update are fired in playback order.
+/// [Seeking](SequenceController::seek) jumps to a time without firing any events,
+/// while [looping](crate::sequence::LoopMode::Loop) fires the events on both sides of the wrap.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::{prelude::*, MotionGfxCorePlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, MotionGfxCorePlugin));
+///
+/// let sequence = Sequence::default().delay(1.0).mark("beat");
+/// let sequence_id = app
+///     .world_mut()
+///     .spawn(SequenceBundle::from_sequence(sequence))
+///     .observe(|trigger: Trigger<SequenceEvent>| {
+///         info!("{:?}", trigger.event());
+///     })
+///     .id();
+///
+/// let mut reader = app.world().resource::<Events<SequenceEvent>>().get_reader();
+///
+/// for (time, direction) in [(1.0, PlaybackDirection::Forward), (0.5, PlaybackDirection::Backward)] {
+///     app.world_mut()
+///         .get_mut::<SequenceController>(sequence_id)
+///         .unwrap()
+///         .target_time = time;
+///     app.update();
+///
+///     let events = app.world().resource::<Events<SequenceEvent>>();
+///     let markers = reader
+///         .read(events)
+///         .filter(|event| event.kind == SequenceEventKind::MarkerCrossed("beat".to_string()))
+///         .collect::<Vec<_>>();
+///     assert_eq!(markers.len(), 1);
+///     assert_eq!(markers[0].direction, direction);
+/// }
+/// ```
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct SequenceEvent {
+    /// The [`Sequence`] entity.
+    pub sequence_id: Entity,
+    pub kind: SequenceEventKind,
+    pub direction: PlaybackDirection,
+    /// The time of the crossed point inside the [`Sequence`].
+    pub time: f32,
+}
+
+/// Send [`SequenceEvent`]s for every point crossed between `curr_time` and `target_time`.
+pub(crate) fn sequence_events(
+    mut commands: Commands,
+    q_sequences: Query<(Entity, &Sequence, &SequenceController)>,
+    mut evw_sequence: EventWriter<SequenceEvent>,
+    mut events: Local<Vec<SequenceEvent>>,
+) {
+    for (sequence_id, sequence, sequence_controller) in q_sequences.iter() {
+        match sequence_controller.wrap {
+            // Wrapping around plays until the end of the range and continues from its start.
+            Some((wrap_end, wrap_start)) => {
+                for (curr_time, target_time) in [
+                    (sequence_controller.curr_time, wrap_end),
+                    (wrap_start, sequence_controller.target_time),
+                ] {
+                    let segment_controller = SequenceController {
+                        curr_time,
+                        target_time,
+                        target_slide_index: sequence_controller.target_slide_index,
+                        ..default()
+                    };
+                    push_events(sequence_id, sequence, &segment_controller, &mut events);
+                }
+            }
+            None if sequence_controller.resample => continue,
+            None => push_events(sequence_id, sequence, sequence_controller, &mut events),
+        }
+
+        for event in events.drain(..) {
+            commands.trigger_targets(event.clone(), sequence_id);
+            evw_sequence.send(event);
+        }
+    }
+}
+
+/// Push [`SequenceEvent`]s in playback order for every point crossed between `curr_time` and `target_time`.
+fn push_events(
+    sequence_id: Entity,
+    sequence: &Sequence,
+    sequence_controller: &SequenceController,
+    events: &mut Vec<SequenceEvent>,
+) {
+    let duration = sequence.duration();
+    let curr_time = sequence_controller.curr_time;
+    let target_time = f32::clamp(sequence_controller.target_time, 0.0, duration);
+    if curr_time == target_time {
+        return;
+    }
+
+    let direction = match target_time > curr_time {
+        true => PlaybackDirection::Forward,
+        false => PlaybackDirection::Backward,
+    };
+
+    // A point is crossed when it is reached, or when it is left from the boundary
+    // of the sequence (points on the boundary cannot be reached from outside).
+    let is_crossed = |time: f32| match direction {
+        PlaybackDirection::Forward => {
+            (time > curr_time || (time == curr_time && curr_time == 0.0)) && time <= target_time
+        }
+        PlaybackDirection::Backward => {
+            (time < curr_time || (time == curr_time && curr_time == duration))
+                && time >= target_time
+        }
+    };
+
+    let first_event = events.len();
+    let mut push_event = |kind: SequenceEventKind, time: f32| {
+        events.push(SequenceEvent {
+            sequence_id,
+            kind,
+            direction,
+            time,
+        });
+    };
+
+    for action_meta in generate_action_iter(sequence, sequence_controller)
+        .into_iter()
+        .flatten()
+    {
+        if is_crossed(action_meta.start_time) {
+            push_event(
+                SequenceEventKind::ActionStarted(action_meta.id()),
+                action_meta.start_time,
+            );
+        }
+
+        if is_crossed(action_meta.end_time()) {
+            push_event(
+                SequenceEventKind::ActionFinished(action_meta.id()),
+                action_meta.end_time(),
+            );
+        }
+    }
+
+    for (name, time) in sequence.markers() {
+        if is_crossed(time) {
+            push_event(SequenceEventKind::MarkerCrossed(name.to_string()), time);
+        }
+    }
+
+    let finish_time = match direction {
+        PlaybackDirection::Forward => duration,
+        PlaybackDirection::Backward => 0.0,
+    };
+    if target_time == finish_time {
+        push_event(SequenceEventKind::Finished, finish_time);
+    }
+
+    // Fire in playback order.
+    let events = &mut events[first_event..];
+    match direction {
+        PlaybackDirection::Forward => events.sort_by(|a, b| f32::total_cmp(&a.time, &b.time)),
+        PlaybackDirection::Backward => events.sort_by(|a, b| f32::total_cmp(&b.time, &a.time)),
+    }
+}
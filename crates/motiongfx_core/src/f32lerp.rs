@@ -7,6 +7,88 @@ pub mod vello_f32lerp;
 #[cfg(feature = "vello_graphics")]
 pub mod vello_graphics_f32lerp;
 
+pub use motiongfx_core_macros::F32Lerp;
+
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::prelude::*;
+///
+/// #[derive(F32Lerp, Clone, Debug, PartialEq)]
+/// struct Label {
+///     position: Vec2,
+///     #[f32lerp(step)]
+///     text: String,
+///     #[f32lerp(skip)]
+///     id: u32,
+///     #[f32lerp(with = lerp_squared)]
+///     size: f32,
+/// }
+///
+/// fn lerp_squared(start: &f32, end: &f32, t: f32) -> f32 {
+///     f32::lerp(*start, *end, t * t)
+/// }
+///
+/// #[derive(F32Lerp, Clone, Debug, PartialEq)]
+/// enum Shape {
+///     Circle { radius: f32 },
+///     Rect(Vec2),
+/// }
+///
+/// let start = Label {
+///     position: Vec2::ZERO,
+///     text: "start".to_string(),
+///     id: 0,
+///     size: 0.0,
+/// };
+/// let end = Label {
+///     position: Vec2::ONE,
+///     text: "end".to_string(),
+///     id: 1,
+///     size: 1.0,
+/// };
+/// assert_eq!(
+///     start.f32lerp(&end, 0.5),
+///     Label {
+///         position: Vec2::splat(0.5),
+///         text: "end".to_string(),
+///         id: 0,
+///         size: 0.25,
+///     }
+/// );
+///
+/// let circle = Shape::Circle { radius: 1.0 };
+/// let rect = Shape::Rect(Vec2::ONE);
+/// assert_eq!(
+///     circle.f32lerp(&Shape::Circle { radius: 2.0 }, 0.5),
+///     Shape::Circle { radius: 1.5 }
+/// );
+/// assert_eq!(circle.f32lerp(&rect, 0.25), circle);
+/// assert_eq!(circle.f32lerp(&rect, 0.75), rect);
+///
+/// // Generic enums are only interpolated when they can be cloned.
+/// #[derive(F32Lerp, Clone, Debug, PartialEq)]
+/// enum Maybe<T> {
+///     Just(T),
+///     Nothing,
+/// }
+///
+/// assert_eq!(Maybe::Just(0.0).f32lerp(&Maybe::Just(1.0), 0.5), Maybe::Just(0.5));
+/// assert_eq!(Maybe::Just(0.0).f32lerp(&Maybe::Nothing, 0.75), Maybe::Nothing);
+/// ```
+///
+/// Enums step between different variants by cloning, so they must implement [`Clone`].
+///
+/// ```compile_fail
+/// use motiongfx_core::prelude::*;
+///
+/// #[derive(F32Lerp)]
+/// enum Shape {
+///     Circle { radius: f32 },
+///     Square { size: f32 },
+/// }
+/// ```
 pub trait F32Lerp<T = Self, U = Self> {
     /// Lerp between 2 values based on a [`f32`] `t` value.
     fn f32lerp(&self, rhs: &T, t: f32) -> U;
@@ -34,3 +116,12 @@ impl F32Lerp for u8 {
         ((other - self_) * t + self_) as u8
     }
 }
+
+/// Step interpolation, jumps from `start` to `end` halfway through.
+#[inline]
+pub fn step<T: Clone>(start: &T, end: &T, t: f32) -> T {
+    match t < 0.5 {
+        true => start.clone(),
+        false => end.clone(),
+    }
+}
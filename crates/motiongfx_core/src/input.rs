@@ -0,0 +1,429 @@
+use bevy::{ecs::system::SystemParam, prelude::*, utils::HashMap};
+
+use crate::{
+    sequence::{
+        sequence_player, PausePlayback, Sequence, SequenceController, SequencePlayer,
+        SequencePlayerState,
+    },
+    slide::{slide_controller, SlideController, SlideTargetState},
+};
+
+/// Drives every [`SequencePlayer`], [`SequenceController`] and [`SlideController`]
+/// from keyboard, mouse and gamepad input based on the [`TimelineInput`] resource.
+///
+/// Controllers that belong to a [`SlideController`] only respond to slide controls.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::{prelude::*, MotionGfxCorePlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, MotionGfxCorePlugin, TimelineInputPlugin))
+///     .init_resource::<ButtonInput<KeyCode>>();
+///
+/// // Paused by other code.
+/// let sequence_id = app
+///     .world_mut()
+///     .spawn((
+///         SequencePlayerBundle {
+///             sequence: Sequence::default().delay(1.0),
+///             sequence_player: SequencePlayer::default().with_time_scale(2.0),
+///             ..default()
+///         },
+///         PausePlayback,
+///     ))
+///     .id();
+///
+/// let mut press = |app: &mut App, key: KeyCode| {
+///     let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+///     keys.clear();
+///     keys.release_all();
+///     keys.press(key);
+///     app.update();
+/// };
+///
+/// // Resumes with the original time scale.
+/// press(&mut app, KeyCode::Space);
+/// assert!(app.world().get::<PausePlayback>(sequence_id).is_none());
+/// assert_eq!(app.world().get::<SequencePlayer>(sequence_id).unwrap().time_scale, 2.0);
+///
+/// press(&mut app, KeyCode::Space);
+/// assert!(app.world().get::<PausePlayback>(sequence_id).is_some());
+/// ```
+pub struct TimelineInputPlugin;
+
+impl Plugin for TimelineInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TimelineInput>().add_systems(
+            Update,
+            (timeline_input, slide_input)
+                .before(sequence_player)
+                .before(slide_controller),
+        );
+    }
+}
+
+/// Controls that can be bound in [`TimelineInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimelineControl {
+    /// Pause or resume the [`SequencePlayer`] by toggling [`PausePlayback`],
+    /// players with a zero time scale resume at normal speed.
+    PlayPause,
+    /// Flip the play direction of the [`SequencePlayer`].
+    Reverse,
+    /// Pause and move forward by [`TimelineInput::step_duration`].
+    StepForward,
+    /// Pause and move backward by [`TimelineInput::step_duration`].
+    StepBackward,
+    /// Jump to the start of the [`Sequence`] or the first slide.
+    JumpToStart,
+    /// Jump to the end of the [`Sequence`] or the last slide.
+    JumpToEnd,
+    /// Move forward by [`TimelineInput::scrub_speed`] while held.
+    ScrubForward,
+    /// Move backward by [`TimelineInput::scrub_speed`] while held.
+    ScrubBackward,
+    /// Multiply the play speed by [`TimelineInput::speed_factor`].
+    SpeedUp,
+    /// Divide the play speed by [`TimelineInput::speed_factor`].
+    SpeedDown,
+    /// Go to the end of the current slide, or to the next slide.
+    NextSlide,
+    /// Go to the start of the current slide, or to the previous slide.
+    PrevSlide,
+}
+
+/// A button that can trigger a [`TimelineControl`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputBinding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    /// Button on any of the connected gamepads.
+    Gamepad(GamepadButtonType),
+}
+
+impl From<KeyCode> for InputBinding {
+    fn from(key: KeyCode) -> Self {
+        Self::Key(key)
+    }
+}
+
+impl From<MouseButton> for InputBinding {
+    fn from(button: MouseButton) -> Self {
+        Self::Mouse(button)
+    }
+}
+
+impl From<GamepadButtonType> for InputBinding {
+    fn from(button: GamepadButtonType) -> Self {
+        Self::Gamepad(button)
+    }
+}
+
+/// Bindings and settings of the [`TimelineInputPlugin`].
+///
+/// The default bindings only use the keyboard and gamepads,
+/// mouse buttons are left unbound so that clicking does not change slides by accident.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::prelude::*;
+///
+/// let mut timeline_input = TimelineInput::default();
+/// timeline_input
+///     .unbind(TimelineControl::PlayPause)
+///     .bind(TimelineControl::PlayPause, KeyCode::KeyP)
+///     .bind(TimelineControl::PlayPause, GamepadButtonType::Start)
+///     // Opt into clicking through slides.
+///     .bind(TimelineControl::NextSlide, MouseButton::Left);
+///
+/// assert_eq!(
+///     timeline_input.bindings(TimelineControl::PlayPause),
+///     [KeyCode::KeyP.into(), GamepadButtonType::Start.into()]
+/// );
+/// ```
+#[derive(Resource, Debug, Clone)]
+pub struct TimelineInput {
+    bindings: HashMap<TimelineControl, Vec<InputBinding>>,
+    /// Time moved by a single step, or a single frame for players with a [fixed fps](SequencePlayer::fixed_fps).
+    pub step_duration: f32,
+    /// Time moved per second while scrubbing.
+    pub scrub_speed: f32,
+    /// Factor applied on the play speed by each speed control.
+    pub speed_factor: f32,
+}
+
+impl TimelineInput {
+    /// Creates a [`TimelineInput`] without any bindings.
+    pub fn empty() -> Self {
+        Self {
+            bindings: HashMap::default(),
+            step_duration: 1.0 / 60.0,
+            scrub_speed: 1.0,
+            speed_factor: 2.0,
+        }
+    }
+
+    /// Add a binding to a control.
+    pub fn bind(
+        &mut self,
+        control: TimelineControl,
+        binding: impl Into<InputBinding>,
+    ) -> &mut Self {
+        self.bindings
+            .entry(control)
+            .or_default()
+            .push(binding.into());
+        self
+    }
+
+    /// Remove all bindings of a control.
+    pub fn unbind(&mut self, control: TimelineControl) -> &mut Self {
+        self.bindings.remove(&control);
+        self
+    }
+
+    pub fn bindings(&self, control: TimelineControl) -> &[InputBinding] {
+        self.bindings
+            .get(&control)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    fn pressed(&self, control: TimelineControl, buttons: &InputButtons) -> bool {
+        self.bindings(control)
+            .iter()
+            .any(|binding| buttons.pressed(binding))
+    }
+
+    fn just_pressed(&self, control: TimelineControl, buttons: &InputButtons) -> bool {
+        self.bindings(control)
+            .iter()
+            .any(|binding| buttons.just_pressed(binding))
+    }
+}
+
+impl Default for TimelineInput {
+    fn default() -> Self {
+        use GamepadButtonType as Pad;
+        use TimelineControl as Ctrl;
+
+        let mut timeline_input = Self::empty();
+        timeline_input
+            .bind(Ctrl::PlayPause, KeyCode::Space)
+            .bind(Ctrl::PlayPause, Pad::South)
+            .bind(Ctrl::Reverse, KeyCode::KeyR)
+            .bind(Ctrl::Reverse, Pad::West)
+            .bind(Ctrl::StepForward, KeyCode::Period)
+            .bind(Ctrl::StepForward, Pad::DPadRight)
+            .bind(Ctrl::StepBackward, KeyCode::Comma)
+            .bind(Ctrl::StepBackward, Pad::DPadLeft)
+            .bind(Ctrl::JumpToStart, KeyCode::Home)
+            .bind(Ctrl::JumpToStart, Pad::Select)
+            .bind(Ctrl::JumpToEnd, KeyCode::End)
+            .bind(Ctrl::JumpToEnd, Pad::Start)
+            .bind(Ctrl::ScrubForward, KeyCode::KeyD)
+            .bind(Ctrl::ScrubForward, Pad::RightTrigger2)
+            .bind(Ctrl::ScrubBackward, KeyCode::KeyA)
+            .bind(Ctrl::ScrubBackward, Pad::LeftTrigger2)
+            .bind(Ctrl::SpeedUp, KeyCode::BracketRight)
+            .bind(Ctrl::SpeedUp, Pad::DPadUp)
+            .bind(Ctrl::SpeedDown, KeyCode::BracketLeft)
+            .bind(Ctrl::SpeedDown, Pad::DPadDown)
+            .bind(Ctrl::NextSlide, KeyCode::ArrowRight)
+            .bind(Ctrl::NextSlide, KeyCode::PageDown)
+            .bind(Ctrl::NextSlide, Pad::RightTrigger)
+            .bind(Ctrl::PrevSlide, KeyCode::ArrowLeft)
+            .bind(Ctrl::PrevSlide, KeyCode::PageUp)
+            .bind(Ctrl::PrevSlide, Pad::LeftTrigger);
+
+        timeline_input
+    }
+}
+
+/// Button states of every input device, missing devices are never pressed.
+#[derive(SystemParam)]
+struct InputButtons<'w> {
+    keys: Option<Res<'w, ButtonInput<KeyCode>>>,
+    mouse_buttons: Option<Res<'w, ButtonInput<MouseButton>>>,
+    gamepads: Option<Res<'w, Gamepads>>,
+    gamepad_buttons: Option<Res<'w, ButtonInput<GamepadButton>>>,
+}
+
+impl InputButtons<'_> {
+    fn pressed(&self, binding: &InputBinding) -> bool {
+        match *binding {
+            InputBinding::Key(key) => self.keys.as_ref().is_some_and(|keys| keys.pressed(key)),
+            InputBinding::Mouse(button) => self
+                .mouse_buttons
+                .as_ref()
+                .is_some_and(|buttons| buttons.pressed(button)),
+            InputBinding::Gamepad(button_type) => self
+                .gamepad_buttons(button_type)
+                .any(|(buttons, button)| buttons.pressed(button)),
+        }
+    }
+
+    fn just_pressed(&self, binding: &InputBinding) -> bool {
+        match *binding {
+            InputBinding::Key(key) => self
+                .keys
+                .as_ref()
+                .is_some_and(|keys| keys.just_pressed(key)),
+            InputBinding::Mouse(button) => self
+                .mouse_buttons
+                .as_ref()
+                .is_some_and(|buttons| buttons.just_pressed(button)),
+            InputBinding::Gamepad(button_type) => self
+                .gamepad_buttons(button_type)
+                .any(|(buttons, button)| buttons.just_pressed(button)),
+        }
+    }
+
+    /// The button of `button_type` on every connected gamepad.
+    fn gamepad_buttons(
+        &self,
+        button_type: GamepadButtonType,
+    ) -> impl Iterator<Item = (&ButtonInput<GamepadButton>, GamepadButton)> {
+        self.gamepads
+            .as_ref()
+            .zip(self.gamepad_buttons.as_ref())
+            .into_iter()
+            .flat_map(move |(gamepads, buttons)| {
+                gamepads
+                    .iter()
+                    .map(move |gamepad| (&**buttons, GamepadButton::new(gamepad, button_type)))
+            })
+    }
+}
+
+/// Apply [`TimelineControl`]s on every [`SequencePlayer`] and [`SequenceController`] outside of slides.
+#[allow(clippy::type_complexity)]
+fn timeline_input(
+    mut commands: Commands,
+    mut q_sequences: Query<
+        (
+            Entity,
+            &Sequence,
+            &mut SequenceController,
+            Option<&mut SequencePlayer>,
+            Option<&mut SequencePlayerState>,
+            Has<PausePlayback>,
+        ),
+        Without<SlideController>,
+    >,
+    timeline_input: Res<TimelineInput>,
+    buttons: InputButtons,
+    time: Res<Time>,
+) {
+    use TimelineControl as Ctrl;
+
+    let pressed = |control| timeline_input.pressed(control, &buttons);
+    let just_pressed = |control| timeline_input.just_pressed(control, &buttons);
+
+    for (id, sequence, mut sequence_controller, sequence_player, player_state, has_pause) in
+        q_sequences.iter_mut()
+    {
+        let mut step_duration = timeline_input.step_duration;
+
+        if let Some(mut sequence_player) = sequence_player {
+            let is_paused = has_pause || sequence_player.time_scale == 0.0;
+
+            if just_pressed(Ctrl::PlayPause) && is_paused {
+                commands.entity(id).remove::<PausePlayback>();
+                if sequence_player.time_scale == 0.0 {
+                    sequence_player.time_scale = 1.0;
+                }
+            } else if (just_pressed(Ctrl::PlayPause)
+                || just_pressed(Ctrl::StepForward)
+                || just_pressed(Ctrl::StepBackward))
+                && is_paused == false
+            {
+                commands.entity(id).insert(PausePlayback);
+            }
+
+            // The time scale is kept while paused, so it can be changed before resuming.
+            if just_pressed(Ctrl::Reverse) {
+                sequence_player.time_scale = -sequence_player.time_scale;
+            }
+            if just_pressed(Ctrl::SpeedUp) {
+                sequence_player.time_scale *= timeline_input.speed_factor;
+            }
+            if just_pressed(Ctrl::SpeedDown) {
+                sequence_player.time_scale /= timeline_input.speed_factor;
+            }
+
+            if let Some(mut player_state) = player_state.filter(|_| just_pressed(Ctrl::JumpToStart))
+            {
+                player_state.reset_loop_count();
+            }
+
+            // Step by a single frame in fixed fps mode.
+            if let Some(fps) = sequence_player.fixed_fps.filter(|&fps| fps > 0) {
+                step_duration = 1.0 / fps as f32;
+            }
+        }
+
+        if just_pressed(Ctrl::StepForward) {
+            sequence_controller.target_time += step_duration;
+        }
+        if just_pressed(Ctrl::StepBackward) {
+            sequence_controller.target_time -= step_duration;
+        }
+
+        let scrub = timeline_input.scrub_speed * time.delta_seconds();
+        if pressed(Ctrl::ScrubForward) {
+            sequence_controller.target_time += scrub;
+        }
+        if pressed(Ctrl::ScrubBackward) {
+            sequence_controller.target_time -= scrub;
+        }
+
+        if just_pressed(Ctrl::JumpToStart) {
+            sequence_controller.seek(0.0);
+        }
+        if just_pressed(Ctrl::JumpToEnd) {
+            sequence_controller.seek(sequence.duration());
+        }
+    }
+}
+
+/// Apply [`TimelineControl`]s on every [`SlideController`].
+fn slide_input(
+    mut q_slides: Query<&mut SlideController>,
+    timeline_input: Res<TimelineInput>,
+    buttons: InputButtons,
+) {
+    use TimelineControl as Ctrl;
+
+    let just_pressed = |control| timeline_input.just_pressed(control, &buttons);
+
+    for mut slide_controller in q_slides.iter_mut() {
+        if just_pressed(Ctrl::NextSlide) {
+            slide_controller.next();
+        }
+        if just_pressed(Ctrl::PrevSlide) {
+            slide_controller.prev();
+        }
+
+        let slide_count = slide_controller.slide_count();
+        if just_pressed(Ctrl::JumpToStart) && slide_count > 0 {
+            slide_controller.seek(0, SlideTargetState::Start);
+        }
+        if just_pressed(Ctrl::JumpToEnd) && slide_count > 0 {
+            slide_controller.seek(slide_count - 1, SlideTargetState::End);
+        }
+
+        let time_scale = slide_controller.time_scale();
+        if just_pressed(Ctrl::SpeedUp) {
+            slide_controller.set_time_scale(time_scale * timeline_input.speed_factor);
+        }
+        if just_pressed(Ctrl::SpeedDown) {
+            slide_controller.set_time_scale(time_scale / timeline_input.speed_factor);
+        }
+    }
+}
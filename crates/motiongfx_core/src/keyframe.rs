@@ -0,0 +1,223 @@
+use bevy::prelude::*;
+
+use crate::{
+    action::{Action, Interp, Motion},
+    ease::{self, cubic, Ease, EaseFn},
+    f32lerp::F32Lerp,
+};
+
+/// A single key inside a [`KeyframeTrack`].
+#[derive(Clone)]
+struct Keyframe<T> {
+    /// Time of the key relative to the start of the track.
+    time: f32,
+    /// Value of the key.
+    value: T,
+    /// Easing function of the segment that ends at this key.
+    ease_fn: Ease,
+}
+
+/// A track of `(time, value, ease)` keys that plays as a single [`Action`].
+///
+/// The ease of a key is applied to the segment that ends at that key.
+/// The track starts with a key at time 0.0 and keys cannot be added before it.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::{prelude::*, MotionGfxCorePlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, MotionGfxCorePlugin))
+///     .register_motion::<Transform, f32>();
+///
+/// let id = app.world_mut().spawn(Transform::default()).id();
+///
+/// let track = KeyframeTrack::new_f32lerp(0.0)
+///     .with_key(1.0, 2.0)
+///     .with_key_ease_fn(3.0, -2.0, ease::linear);
+///
+/// let mut commands = app.world_mut().commands();
+/// let sequence = commands.play_motion(
+///     track.animate(id, |transform: &mut Transform| &mut transform.translation.x),
+/// );
+/// let sequence_id = commands.spawn(SequenceBundle::from_sequence(sequence)).id();
+/// app.world_mut().flush();
+///
+/// // Forwards and then backwards.
+/// for (time, x) in [(0.5, 1.0), (1.0, 2.0), (2.0, 0.0), (3.0, -2.0), (1.0, 2.0), (0.0, 0.0)] {
+///     let mut controller = app.world_mut().get_mut::<SequenceController>(sequence_id).unwrap();
+///     controller.target_time = time;
+///     app.update();
+///
+///     let transform = app.world().get::<Transform>(id).unwrap();
+///     assert!(f32::abs(transform.translation.x - x) < 1e-5);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct KeyframeTrack<T> {
+    /// Keys sorted by time.
+    keys: Vec<Keyframe<T>>,
+    /// Function for interpolating between 2 keys.
+    interp_fn: Interp<T>,
+}
+
+impl<T> KeyframeTrack<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// Creates a new [`KeyframeTrack`] with `value` as the first key at time 0.0.
+    pub fn new<I>(value: T, interp_fn: I) -> Self
+    where
+        I: Fn(&T, &T, f32) -> T + Send + Sync + 'static,
+    {
+        Self {
+            keys: vec![Keyframe {
+                time: 0.0,
+                value,
+                ease_fn: Ease::Fn(ease::linear),
+            }],
+            interp_fn: Interp::new(interp_fn),
+        }
+    }
+
+    /// Add a key using the default easing function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `time` is negative or not finite.
+    pub fn with_key(self, time: f32, value: T) -> Self {
+        self.with_key_ease_fn(time, value, cubic::ease_in_out)
+    }
+
+    /// Add a key with an easing function for the segment that ends at this key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `time` is negative or not finite.
+    pub fn with_key_ease<E>(self, time: f32, value: T, ease_fn: E) -> Self
+    where
+        E: Fn(f32) -> f32 + Send + Sync + 'static,
+    {
+        self.insert_key(time, value, Ease::new(ease_fn))
+    }
+
+    /// Add a key with a plain [`EaseFn`] (fast path) for the segment that ends at this key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `time` is negative or not finite.
+    pub fn with_key_ease_fn(self, time: f32, value: T, ease_fn: EaseFn) -> Self {
+        self.insert_key(time, value, Ease::from_fn(ease_fn))
+    }
+
+    fn insert_key(mut self, time: f32, value: T, ease_fn: Ease) -> Self {
+        assert_key_time(time);
+
+        // Keys with the same time keep their insertion order.
+        let index = self.keys.partition_point(|key| key.time <= time);
+        self.keys.insert(
+            index,
+            Keyframe {
+                time,
+                value,
+                ease_fn,
+            },
+        );
+        self
+    }
+
+    /// Time of the last key.
+    pub fn duration(&self) -> f32 {
+        self.keys[self.keys.len() - 1].time
+    }
+
+    /// Value of the last key.
+    pub fn end(&self) -> &T {
+        &self.keys[self.keys.len() - 1].value
+    }
+
+    /// Sample the value of the track at `time`.
+    pub fn sample(&self, time: f32) -> T {
+        sample_keys(&self.keys, &self.interp_fn, time)
+    }
+
+    /// Convert the [`KeyframeTrack`] into an [`Action`] that samples the track.
+    ///
+    /// The [`Action`] uses a linear easing function, any other easing function
+    /// warps the timing of the whole track.
+    pub fn into_action<U, F>(self, target_id: Entity, get_field_fn: F) -> Action<T, U>
+    where
+        U: 'static,
+        F: Fn(&mut U) -> &mut T + Send + Sync + 'static,
+    {
+        let duration = self.duration();
+        let start = self.keys[0].value.clone();
+        let end = self.end().clone();
+        let Self { keys, interp_fn } = self;
+
+        Action::new(
+            target_id,
+            start,
+            end,
+            move |_: &T, _: &T, t| sample_keys(&keys, &interp_fn, t * duration),
+            get_field_fn,
+        )
+        .with_ease_fn(ease::linear)
+    }
+
+    /// Convert the [`KeyframeTrack`] into a [`Motion`] that lasts until the last key.
+    pub fn animate<U, F>(self, target_id: Entity, get_field_fn: F) -> Motion<T, U>
+    where
+        U: 'static,
+        F: Fn(&mut U) -> &mut T + Send + Sync + 'static,
+    {
+        let duration = self.duration();
+        self.into_action(target_id, get_field_fn).animate(duration)
+    }
+}
+
+impl<T> KeyframeTrack<T>
+where
+    T: F32Lerp + Clone + Send + Sync + 'static,
+{
+    /// Creates a new [`KeyframeTrack`] with [`F32Lerp`] as the
+    /// [interpolation function](Interp).
+    pub fn new_f32lerp(value: T) -> Self {
+        Self {
+            keys: vec![Keyframe {
+                time: 0.0,
+                value,
+                ease_fn: Ease::Fn(ease::linear),
+            }],
+            interp_fn: Interp::Fn(T::f32lerp),
+        }
+    }
+}
+
+/// Keys before the first key at time 0.0 would never be reached.
+fn assert_key_time(time: f32) {
+    assert!(
+        time >= 0.0 && time.is_finite(),
+        "The time of a key must be non-negative and finite, got {time}."
+    );
+}
+
+fn sample_keys<T: Clone>(keys: &[Keyframe<T>], interp_fn: &Interp<T>, time: f32) -> T {
+    // Index of the first key after `time`.
+    let index = keys.partition_point(|key| key.time <= time);
+
+    if index == 0 {
+        return keys[0].value.clone();
+    }
+    if index == keys.len() {
+        return keys[index - 1].value.clone();
+    }
+
+    let prev = &keys[index - 1];
+    let next = &keys[index];
+    let t = (time - prev.time) / (next.time - prev.time);
+
+    interp_fn.interp(&prev.value, &next.value, next.ease_fn.ease(t))
+}
@@ -1,39 +1,90 @@
 use bevy::prelude::*;
-use sequence::{sequence_controller, sequence_player};
+use clock::sync_clock_links;
+use conflict::{detect_conflicts, ConflictEvent, ConflictPolicy};
+use event::{sequence_events, SequenceEvent};
+use registry::{warn_unregistered_actions, MotionRegistry};
+use sequence::{seek_markers, sequence_controller, sequence_player, update_sequences};
 use slide::slide_controller;
 
+// Lets the derive macros refer to `::motiongfx_core` from inside this crate.
+extern crate self as motiongfx_core;
+
 pub mod action;
+pub mod clock;
 pub mod color_palette;
+pub mod conflict;
 pub mod ease;
+pub mod event;
 pub mod f32lerp;
+pub mod input;
+pub mod keyframe;
+pub mod registry;
 pub mod sequence;
 pub mod slide;
+pub mod stagger;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod tuple_motion;
 
+// Keeps the `motiongfx_core::act!` path of the former `macro_rules!` macro.
+pub use action::act;
+
 pub mod prelude {
     pub use crate::{
         action::{act, Action, SequenceBuilderExt},
+        clock::ClockLink,
         color_palette::{ColorKey, ColorPalette},
+        conflict::ConflictPolicy,
         ease,
+        event::{PlaybackDirection, SequenceEvent, SequenceEventKind},
         f32lerp::F32Lerp,
+        input::{InputBinding, TimelineControl, TimelineInput, TimelineInputPlugin},
+        keyframe::KeyframeTrack,
+        registry::{MotionAppExt, MotionRegistry, MotionTarget},
         sequence::{
-            all, any, chain, delay, flow, update_asset, update_component, MultiSeqOrd, Sequence,
-            SequenceBundle, SequenceController, SequencePlayer, SequencePlayerBundle, SingleSeqOrd,
+            all, any, chain, delay, flow, LoopMode, MultiSeqOrd, PausePlayback, Sequence,
+            SequenceBundle, SequenceClip, SequenceController, SequencePlayer, SequencePlayerBundle,
+            SequencePlayerState, SingleSeqOrd,
         },
         slide::{create_slide, SlideBundle, SlideController, SlideCurrState, SlideTargetState},
+        stagger::Stagger,
         tuple_motion::{GetId, GetMut, GetMutValue},
     };
+
+    #[allow(deprecated)]
+    pub use crate::sequence::{update_asset, update_component};
 }
 
 pub struct MotionGfxCorePlugin;
 
 impl Plugin for MotionGfxCorePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (sequence_player, slide_controller).before(UpdateSequenceSet),
-        )
-        .add_systems(Update, sequence_controller.after(UpdateSequenceSet));
+        app.init_resource::<MotionRegistry>()
+            .init_resource::<ConflictPolicy>()
+            .add_event::<SequenceEvent>()
+            .add_event::<ConflictEvent>()
+            .add_systems(
+                Update,
+                (
+                    (sequence_player, slide_controller),
+                    seek_markers,
+                    sync_clock_links,
+                )
+                    .chain()
+                    .before(UpdateSequenceSet),
+            )
+            .add_systems(
+                Update,
+                (
+                    warn_unregistered_actions,
+                    detect_conflicts,
+                    update_sequences,
+                    sequence_events,
+                )
+                    .chain()
+                    .in_set(UpdateSequenceSet),
+            )
+            .add_systems(Update, sequence_controller.after(UpdateSequenceSet));
     }
 }
 
@@ -0,0 +1,327 @@
+use std::any::TypeId;
+
+use bevy::{
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+
+use crate::{action::Action, sequence::Sequence};
+
+pub use motiongfx_core_macros::MotionTarget;
+
+/// Type-erased function that applies an [`Action`] at a given (un-eased) unit time.
+///
+/// Additive actions apply the change in value since `prev_unit_time`.
+pub(crate) type ApplyFn =
+    fn(world: &mut World, action_id: Entity, prev_unit_time: f32, unit_time: f32);
+
+/// Registry of type-erased appliers for every [`Action`] type that can be animated.
+///
+/// A single evaluation pass walks through every [`Sequence`]
+/// and dispatches each [`Action`] to the applier registered for its type.
+///
+/// Registering the same [`Action`] type more than once has no effect.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::prelude::*;
+///
+/// let mut registry = MotionRegistry::default();
+/// registry
+///     .register_component::<Transform, Vec3>()
+///     .register_component::<Transform, f32>();
+///
+/// assert!(registry.contains::<Transform, Vec3>());
+/// assert!(!registry.contains::<Transform, Quat>());
+/// ```
+#[derive(Resource, Default)]
+pub struct MotionRegistry {
+    /// Appliers keyed by the [`TypeId`] of the [`Action`].
+    appliers: HashMap<TypeId, ApplyFn>,
+}
+
+impl MotionRegistry {
+    /// Register [`Action<T, U>`] where `U` is a [`Component`].
+    pub fn register_component<U, T>(&mut self) -> &mut Self
+    where
+        U: Component,
+        T: Send + Sync + 'static,
+    {
+        self.register::<U, T>(apply_component::<U, T>)
+    }
+
+    /// Register [`Action<T, U>`] where `U` is an [`Asset`].
+    pub fn register_asset<U, T>(&mut self) -> &mut Self
+    where
+        U: Asset,
+        T: Send + Sync + 'static,
+    {
+        self.register::<U, T>(apply_asset::<U, T>)
+    }
+
+    /// Register [`Action<T, U>`] where `U` is a [`Resource`].
+    ///
+    /// The `target_id` of the [`Action`] is ignored, [`Entity::PLACEHOLDER`] can be used instead.
+    pub fn register_resource<U, T>(&mut self) -> &mut Self
+    where
+        U: Resource,
+        T: Send + Sync + 'static,
+    {
+        self.register::<U, T>(apply_resource::<U, T>)
+    }
+
+    /// Returns true if [`Action<T, U>`] has been registered.
+    pub fn contains<U, T>(&self) -> bool
+    where
+        U: 'static,
+        T: 'static,
+    {
+        self.appliers.contains_key(&TypeId::of::<Action<T, U>>())
+    }
+
+    fn register<U, T>(&mut self, apply_fn: ApplyFn) -> &mut Self
+    where
+        U: 'static,
+        T: 'static,
+    {
+        // The first registration wins.
+        self.appliers
+            .entry(TypeId::of::<Action<T, U>>())
+            .or_insert(apply_fn);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn get(&self, type_id: &TypeId) -> Option<&ApplyFn> {
+        self.appliers.get(type_id)
+    }
+}
+
+/// A [`Component`] or [`Asset`] with generated motion builders, see [`derive@MotionTarget`].
+///
+/// Registration takes the derive plus one line, `app.register_motion_target::<T>()`,
+/// as Rust can not run code for a type on its own. The derive only generates [`MotionTarget::register`],
+/// which [`MotionAppExt::register_motion_target`] runs once when building the [`App`].
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::{prelude::*, MotionGfxCorePlugin};
+///
+/// #[derive(Component, MotionTarget, Clone)]
+/// struct Health {
+///     value: f32,
+///     color: Color,
+///     #[motion_target(skip)]
+///     name: String,
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, MotionGfxCorePlugin))
+///     .register_motion_target::<Health>();
+///
+/// let mut health = Health {
+///     value: 100.0,
+///     color: Color::WHITE,
+///     name: "Player".to_string(),
+/// };
+/// let mut player = (app.world_mut().spawn(health.clone()).id(), health);
+///
+/// let sequence = app.world_mut().commands().play_motion(
+///     player
+///         .health()
+///         .to_value(0.0)
+///         .with_ease_fn(ease::linear)
+///         .animate(1.0),
+/// );
+/// let sequence_id = app.world_mut().spawn(SequenceBundle::from_sequence(sequence)).id();
+///
+/// app.world_mut()
+///     .get_mut::<SequenceController>(sequence_id)
+///     .unwrap()
+///     .target_time = 0.25;
+/// app.update();
+///
+/// assert_eq!(app.world().get::<Health>(player.0).unwrap().value, 75.0);
+/// let registry = app.world().resource::<MotionRegistry>();
+/// assert!(registry.contains::<Health, Color>());
+/// // No builder animates the whole `Health` at once.
+/// assert!(registry.contains::<Health, Health>() == false);
+/// ```
+pub trait MotionTarget: Send + Sync + 'static {
+    /// Register every [`Action`] type that the motion builders can create.
+    fn register(registry: &mut MotionRegistry);
+}
+
+pub trait MotionAppExt {
+    /// Register [`Action<T, U>`] where `U` is a [`Component`].
+    fn register_motion<U, T>(&mut self) -> &mut Self
+    where
+        U: Component,
+        T: Send + Sync + 'static;
+
+    /// Register [`Action<T, U>`] where `U` is an [`Asset`].
+    fn register_asset_motion<U, T>(&mut self) -> &mut Self
+    where
+        U: Asset,
+        T: Send + Sync + 'static;
+
+    /// Register [`Action<T, U>`] where `U` is a [`Resource`].
+    fn register_resource_motion<U, T>(&mut self) -> &mut Self
+    where
+        U: Resource,
+        T: Send + Sync + 'static;
+
+    /// Register every [`Action`] type of a [`MotionTarget`].
+    fn register_motion_target<M: MotionTarget>(&mut self) -> &mut Self;
+}
+
+impl MotionAppExt for App {
+    fn register_motion<U, T>(&mut self) -> &mut Self
+    where
+        U: Component,
+        T: Send + Sync + 'static,
+    {
+        self.world_mut()
+            .get_resource_or_insert_with(MotionRegistry::default)
+            .register_component::<U, T>();
+        self
+    }
+
+    fn register_asset_motion<U, T>(&mut self) -> &mut Self
+    where
+        U: Asset,
+        T: Send + Sync + 'static,
+    {
+        self.world_mut()
+            .get_resource_or_insert_with(MotionRegistry::default)
+            .register_asset::<U, T>();
+        self
+    }
+
+    fn register_resource_motion<U, T>(&mut self) -> &mut Self
+    where
+        U: Resource,
+        T: Send + Sync + 'static,
+    {
+        self.world_mut()
+            .get_resource_or_insert_with(MotionRegistry::default)
+            .register_resource::<U, T>();
+        self
+    }
+
+    fn register_motion_target<M: MotionTarget>(&mut self) -> &mut Self {
+        M::register(
+            &mut self
+                .world_mut()
+                .get_resource_or_insert_with(MotionRegistry::default),
+        );
+        self
+    }
+}
+
+/// Warn once for every [`Action`] type inside newly added [`Sequence`]s that is not
+/// registered in the [`MotionRegistry`], as those [`Action`]s will never be applied.
+pub(crate) fn warn_unregistered_actions(
+    q_sequences: Query<&Sequence, Added<Sequence>>,
+    registry: Res<MotionRegistry>,
+    mut reported: Local<HashSet<TypeId>>,
+) {
+    for sequence in q_sequences.iter() {
+        sequence.for_each_action_meta(&mut |action_meta| {
+            let type_id = action_meta.action_type_id();
+
+            if registry.appliers.contains_key(&type_id) || reported.insert(type_id) == false {
+                return;
+            }
+
+            warn!(
+                "`{}` is not registered and will not be animated. \
+                Register it using `App::register_motion` or its variants.",
+                action_meta.action_type_name()
+            );
+        });
+    }
+}
+
+/// Apply an [`Action`] on a [`Component`].
+fn apply_component<U, T>(world: &mut World, action_id: Entity, prev_unit_time: f32, unit_time: f32)
+where
+    U: Component,
+    T: Send + Sync + 'static,
+{
+    // Ignore if `Action` does not exists
+    let Some(action) = world.get::<Action<T, U>>(action_id) else {
+        return;
+    };
+
+    let target_id = action.target_id;
+    let get_field_fn = action.get_field_fn.clone();
+    // Calculate unit time using ease function and interpolate the value
+    let value = action.sample(prev_unit_time, unit_time);
+
+    // Get component to mutate based on target id
+    let Some(mut component) = world.get_mut::<U>(target_id) else {
+        return;
+    };
+
+    value.write(get_field_fn.get_mut(&mut component));
+}
+
+/// Apply an [`Action`] on an [`Asset`].
+fn apply_asset<U, T>(world: &mut World, action_id: Entity, prev_unit_time: f32, unit_time: f32)
+where
+    U: Asset,
+    T: Send + Sync + 'static,
+{
+    // Ignore if `Action` does not exists
+    let Some(action) = world.get::<Action<T, U>>(action_id) else {
+        return;
+    };
+
+    let target_id = action.target_id;
+    let get_field_fn = action.get_field_fn.clone();
+    // Calculate unit time using ease function and interpolate the value
+    let value = action.sample(prev_unit_time, unit_time);
+
+    // Get handle based on target id
+    let Some(handle) = world.get::<Handle<U>>(target_id) else {
+        return;
+    };
+    let handle = handle.clone_weak();
+
+    // Get asset to mutate based on the handle id
+    let Some(mut assets) = world.get_resource_mut::<Assets<U>>() else {
+        return;
+    };
+    let Some(asset) = assets.get_mut(&handle) else {
+        return;
+    };
+
+    value.write(get_field_fn.get_mut(asset));
+}
+
+/// Apply an [`Action`] on a [`Resource`].
+fn apply_resource<U, T>(world: &mut World, action_id: Entity, prev_unit_time: f32, unit_time: f32)
+where
+    U: Resource,
+    T: Send + Sync + 'static,
+{
+    // Ignore if `Action` does not exists
+    let Some(action) = world.get::<Action<T, U>>(action_id) else {
+        return;
+    };
+
+    let get_field_fn = action.get_field_fn.clone();
+    // Calculate unit time using ease function and interpolate the value
+    let value = action.sample(prev_unit_time, unit_time);
+
+    let Some(mut resource) = world.get_resource_mut::<U>() else {
+        return;
+    };
+
+    value.write(get_field_fn.get_mut(&mut resource));
+}
@@ -1,6 +1,24 @@
-use bevy::prelude::*;
+use std::{any::TypeId, ops::Range};
 
-use crate::action::{Action, ActionMeta};
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::{
+    action::ActionMeta,
+    conflict::is_field_overlap,
+    ease::{Ease, EaseFn},
+    registry::MotionRegistry,
+    stagger::{self, Stagger},
+};
+
+use action_index::ActionIndex;
+use clip::push_updates;
+use time_warp::first_time;
+
+pub use clip::SequenceClip;
+
+mod action_index;
+mod clip;
+pub(crate) mod time_warp;
 
 /// Bundle to encapsulate [`Sequence`] and [`SequenceController`].
 #[derive(Bundle, Default)]
@@ -40,18 +58,44 @@ impl SequencePlayerBundle {
 pub struct Sequence {
     duration: f32,
     // TODO(perf): Use SmallVec to prevent heap allocations for single action sequences.
+    /// [`ActionMeta`]s sorted by their start time.
     pub(crate) action_metas: Vec<ActionMeta>,
+    /// Interval index for fast lookup of [`ActionMeta`]s within a time range.
+    index: ActionIndex,
+    /// Named points in time.
+    markers: Vec<Marker>,
+    /// [`Sequence`]s embedded with their own clock.
+    clips: Vec<SequenceClip>,
+}
+
+/// A named point in time inside a [`Sequence`].
+#[derive(Clone)]
+struct Marker {
+    name: String,
+    time: f32,
 }
 
 impl Sequence {
-    pub(crate) fn single(action_meta: ActionMeta) -> Self {
-        let duration = action_meta.duration;
+    /// Creates a new [`Sequence`], sorting the [`ActionMeta`]s and building the [`ActionIndex`].
+    pub(crate) fn new(mut action_metas: Vec<ActionMeta>, duration: f32) -> Self {
+        // Stable sort to preserve the order of actions that start at the same time.
+        action_metas.sort_by(|a, b| f32::total_cmp(&a.start_time, &b.start_time));
+        let index = ActionIndex::new(&action_metas);
+
         Self {
-            action_metas: vec![action_meta],
             duration,
+            action_metas,
+            index,
+            markers: Vec::new(),
+            clips: Vec::new(),
         }
     }
 
+    pub(crate) fn single(action_meta: ActionMeta) -> Self {
+        let duration = action_meta.duration;
+        Self::new(vec![action_meta], duration)
+    }
+
     pub(crate) fn empty(duration: f32) -> Self {
         Self {
             duration,
@@ -63,12 +107,113 @@ impl Sequence {
         for action_meta in &mut self.action_metas {
             action_meta.slide_index = slide_index;
         }
+
+        for clip in &mut self.clips {
+            clip.sequence.set_slide_index(slide_index);
+        }
     }
 
     #[inline]
     pub fn duration(&self) -> f32 {
         self.duration
     }
+
+    /// Indices of the [`ActionMeta`]s that overlap `[begin, end]`, looked up through the [`ActionIndex`].
+    #[doc(hidden)]
+    pub fn query_action_indices(&self, begin: f32, end: f32) -> impl Iterator<Item = usize> + '_ {
+        self.index.query(&self.action_metas, begin, end, false)
+    }
+
+    /// Same as [`Self::query_action_indices`] with a linear scan, used as a benchmark baseline.
+    #[doc(hidden)]
+    pub fn scan_action_indices(&self, begin: f32, end: f32) -> impl Iterator<Item = usize> + '_ {
+        self.action_metas
+            .iter()
+            .enumerate()
+            .filter(move |(_, action_meta)| {
+                action_meta.start_time <= end && action_meta.end_time() >= begin
+            })
+            .map(|(index, _)| index)
+    }
+
+    /// Add a named marker at the end of the [`Sequence`].
+    ///
+    /// Markers are shifted together with the actions when the [`Sequence`] is combined with others.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use motiongfx_core::prelude::*;
+    ///
+    /// let intro = Sequence::default().mark("intro_start");
+    /// let sequence = [
+    ///     intro,
+    ///     Sequence::default().delay(2.0).mark("intro_done"),
+    /// ]
+    /// .chain()
+    /// .delay(1.0);
+    ///
+    /// assert_eq!(sequence.marker_time("intro_start"), Some(1.0));
+    /// assert_eq!(sequence.marker_time("intro_done"), Some(3.0));
+    /// assert_eq!(sequence.marker_time("outro"), None);
+    /// ```
+    pub fn mark(self, name: impl Into<String>) -> Self {
+        let time = self.duration;
+        self.mark_at(name, time)
+    }
+
+    /// Add a named marker at `time` relative to the start of the [`Sequence`].
+    pub fn mark_at(mut self, name: impl Into<String>, time: f32) -> Self {
+        self.markers.push(Marker {
+            name: name.into(),
+            time,
+        });
+        self
+    }
+
+    /// Time of the earliest marker with the given `name`.
+    pub fn marker_time(&self, name: &str) -> Option<f32> {
+        self.markers
+            .iter()
+            .filter(|marker| marker.name == name)
+            .map(|marker| marker.time)
+            .min_by(f32::total_cmp)
+    }
+
+    /// Iterate over the names and times of all markers.
+    pub fn markers(&self) -> impl Iterator<Item = (&str, f32)> {
+        self.markers
+            .iter()
+            .map(|marker| (marker.name.as_str(), marker.time))
+    }
+
+    /// Copy the markers of a [`Sequence`] with their times remapped, dropping markers that map to `None`.
+    fn with_markers_of(
+        mut self,
+        sequence: &Sequence,
+        map_time: impl Fn(f32) -> Option<f32>,
+    ) -> Self {
+        self.markers = sequence
+            .markers
+            .iter()
+            .filter_map(|marker| {
+                Some(Marker {
+                    name: marker.name.clone(),
+                    time: map_time(marker.time)?,
+                })
+            })
+            .collect();
+        self
+    }
+
+    /// Append the markers of a [`Sequence`] shifted by `offset`.
+    pub(crate) fn extend_markers(&mut self, sequence: &Sequence, offset: f32) {
+        self.markers
+            .extend(sequence.markers.iter().map(|marker| Marker {
+                name: marker.name.clone(),
+                time: marker.time + offset,
+            }));
+    }
 }
 
 /// Plays the [`Sequence`] component attached to this entity through `target_time` manipulation.
@@ -79,12 +224,283 @@ pub struct SequenceController {
     pub target_time: f32,
     /// Target slide index to reach (and not exceed).
     pub target_slide_index: usize,
+    /// Evaluate the entire [`Sequence`] from scratch on the next update.
+    pub(crate) resample: bool,
+    /// Marker to seek to on the next update.
+    pub(crate) target_marker: Option<String>,
+    /// Boundary of the play range that the playhead wrapped around from,
+    /// and the boundary it continued from, for firing events across loops.
+    pub(crate) wrap: Option<(f32, f32)>,
+}
+
+impl SequenceController {
+    /// Jump to `time` and evaluate every action in the [`Sequence`] from scratch.
+    ///
+    /// Unlike changing `target_time` directly, the result does not depend on the
+    /// previous frames: every animated field is set to the exact value it holds at `time`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use motiongfx_core::{prelude::*, MotionGfxCorePlugin};
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins((MinimalPlugins, MotionGfxCorePlugin))
+    ///     .register_motion::<Transform, f32>();
+    ///
+    /// let mut transform = Transform::default();
+    /// let id = app.world_mut().spawn(transform).id();
+    ///
+    /// let mut commands = app.world_mut().commands();
+    /// let sequence = [1.0, 3.0, 2.0]
+    ///     .map(|x| {
+    ///         commands.play_motion(
+    ///             act!((id, Transform), start = { transform }.translation.x, end = x,)
+    ///                 .with_ease_fn(ease::linear)
+    ///                 .animate(1.0),
+    ///         )
+    ///     })
+    ///     .flow(2.0);
+    /// let sequence_id = commands.spawn(SequenceBundle::from_sequence(sequence)).id();
+    /// app.world_mut().flush();
+    ///
+    /// // Forward, backward and across the gaps between the actions.
+    /// for (time, x) in [(2.5, 2.0), (5.0, 2.0), (0.5, 0.5), (4.5, 2.5), (1.5, 1.0), (0.0, 0.0)] {
+    ///     let mut controller = app.world_mut().get_mut::<SequenceController>(sequence_id).unwrap();
+    ///     controller.seek(time);
+    ///     app.update();
+    ///
+    ///     let transform = app.world().get::<Transform>(id).unwrap();
+    ///     assert!(f32::abs(transform.translation.x - x) < 1e-5);
+    /// }
+    /// ```
+    pub fn seek(&mut self, time: f32) {
+        self.target_time = time;
+        self.resample = true;
+        self.wrap = None;
+    }
+
+    /// Evaluate every action in the [`Sequence`] from scratch at `target_time` on the next update.
+    pub fn resample(&mut self) {
+        self.resample = true;
+    }
+
+    /// [Seek](Self::seek) to the time of a named marker in the [`Sequence`] on the next update.
+    ///
+    /// A warning is logged if the marker does not exist.
+    pub fn seek_marker(&mut self, name: impl Into<String>) {
+        self.target_marker = Some(name.into());
+    }
 }
 
 /// Manipulates the `target_time` variable of the [`SequenceController`] component attached to this entity with a `time_scale`.
 #[derive(Component, Default)]
 pub struct SequencePlayer {
     pub time_scale: f32,
+    /// What to do when reaching either end of the play range.
+    pub loop_mode: LoopMode,
+    /// Region of the [`Sequence`] to play in, the entire [`Sequence`] is played if `None`.
+    pub play_range: Option<Range<f32>>,
+    /// Advance by whole frames of this frame rate on every update instead of the frame delta time.
+    ///
+    /// The `time_scale` is rounded to the number of frames to advance per update.
+    pub fixed_fps: Option<u32>,
+}
+
+impl SequencePlayer {
+    pub fn with_time_scale(mut self, time_scale: f32) -> Self {
+        self.time_scale = time_scale;
+        self
+    }
+
+    pub fn with_loop_mode(mut self, loop_mode: LoopMode) -> Self {
+        self.loop_mode = loop_mode;
+        self
+    }
+
+    pub fn with_play_range(mut self, play_range: Range<f32>) -> Self {
+        self.play_range = Some(play_range);
+        self
+    }
+
+    /// Advance exactly `round(time_scale)` frames of `fps` on every update.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use motiongfx_core::{prelude::*, MotionGfxCorePlugin};
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins((MinimalPlugins, MotionGfxCorePlugin));
+    ///
+    /// let sequence_id = app
+    ///     .world_mut()
+    ///     .spawn(SequencePlayerBundle {
+    ///         sequence: Sequence::default().delay(1.0),
+    ///         sequence_player: SequencePlayer::default()
+    ///             .with_time_scale(1.0)
+    ///             .with_fixed_fps(30),
+    ///         ..default()
+    ///     })
+    ///     .id();
+    ///
+    /// for frame in 1..=40 {
+    ///     app.update();
+    ///
+    ///     let state = app.world().get::<SequencePlayerState>(sequence_id).unwrap();
+    ///     let controller = app.world().get::<SequenceController>(sequence_id).unwrap();
+    ///     assert_eq!(state.frame(), u32::min(frame, 30));
+    ///     assert_eq!(controller.target_time, f32::min(frame as f32 / 30.0, 1.0));
+    /// }
+    /// ```
+    pub fn with_fixed_fps(mut self, fps: u32) -> Self {
+        self.fixed_fps = Some(fps);
+        self
+    }
+
+    /// Play range clamped within the `duration` of the [`Sequence`].
+    fn clamped_range(&self, duration: f32) -> (f32, f32) {
+        match &self.play_range {
+            Some(range) => {
+                let start = f32::clamp(range.start, 0.0, duration);
+                (start, f32::clamp(range.end, start, duration))
+            }
+            None => (0.0, duration),
+        }
+    }
+}
+
+/// Counters of a [`SequencePlayer`], added to its entity on the first update.
+#[derive(Component, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SequencePlayerState {
+    /// Number of times the player wrapped around or bounced.
+    loop_count: u32,
+    /// Frame index of the target time after the last update.
+    frame: u32,
+}
+
+impl SequencePlayerState {
+    /// Frame index of the target time after the last update,
+    /// only counted with a [fixed fps](SequencePlayer::fixed_fps).
+    pub fn frame(&self) -> u32 {
+        self.frame
+    }
+
+    /// Number of times the player wrapped around or bounced.
+    pub fn loop_count(&self) -> u32 {
+        self.loop_count
+    }
+
+    /// Reset the loop count, e.g. to play a [`LoopMode::Repeat`] again.
+    pub fn reset_loop_count(&mut self) {
+        self.loop_count = 0;
+    }
+}
+
+/// Stops the [`SequencePlayer`] or [`SlideController`](crate::slide::SlideController)
+/// of this entity from moving the time, without changing their state.
+#[derive(Component, Default, Debug, Clone, Copy)]
+pub struct PausePlayback;
+
+/// How a [`SequencePlayer`] behaves when it reaches either end of its play range.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::{prelude::*, MotionGfxCorePlugin};
+///
+/// #[derive(Resource, Default)]
+/// struct Beats(u32);
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, MotionGfxCorePlugin))
+///     .init_resource::<Beats>()
+///     .observe(|trigger: Trigger<SequenceEvent>, mut beats: ResMut<Beats>| {
+///         if trigger.event().kind == SequenceEventKind::MarkerCrossed("beat".to_string()) {
+///             beats.0 += 1;
+///         }
+///     });
+///
+/// let sequence = Sequence::default().delay(1.0).mark_at("beat", 0.0625);
+/// app.world_mut().spawn(SequencePlayerBundle {
+///     sequence,
+///     sequence_player: SequencePlayer::default()
+///         .with_time_scale(1.0)
+///         .with_loop_mode(LoopMode::Loop)
+///         .with_fixed_fps(10),
+///     ..default()
+/// });
+///
+/// // 4 loops of 10 frames, the marker is crossed right after each wrap.
+/// for _ in 0..40 {
+///     app.update();
+/// }
+/// assert_eq!(app.world().resource::<Beats>().0, 4);
+/// ```
+///
+/// Every wrap [seeks](SequenceController::seek) to the wrapped time,
+/// so additive actions start over from the same value on every loop.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::{prelude::*, MotionGfxCorePlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, MotionGfxCorePlugin))
+///     .register_motion::<Transform, f32>();
+///
+/// let id = app.world_mut().spawn(Transform::default()).id();
+///
+/// let offset = Action::new_f32lerp(id, 0.0, 1.0, |transform: &mut Transform| {
+///     &mut transform.translation.x
+/// })
+/// .with_ease_fn(ease::linear)
+/// .additive()
+/// .animate(1.0);
+///
+/// let mut commands = app.world_mut().commands();
+/// let sequence = [Sequence::default().delay(0.5), commands.play_motion(offset)].chain();
+/// let sequence_id = commands
+///     .spawn(SequencePlayerBundle {
+///         sequence,
+///         sequence_player: SequencePlayer::default()
+///             .with_time_scale(1.0)
+///             .with_loop_mode(LoopMode::Loop)
+///             .with_fixed_fps(10),
+///         ..default()
+///     })
+///     .id();
+/// app.world_mut().flush();
+///
+/// let mut loop_count = 0;
+/// for _ in 0..50 {
+///     app.update();
+///
+///     let x = app.world().get::<Transform>(id).unwrap().translation.x;
+///     let state = app.world().get::<SequencePlayerState>(sequence_id).unwrap();
+///     if state.loop_count() > loop_count {
+///         // Wrapped around to 0.1 seconds, before the additive action starts.
+///         loop_count = state.loop_count();
+///         assert!(f32::abs(x) < 1e-5);
+///     }
+///     assert!(x < 1.0 + 1e-5);
+/// }
+/// assert_eq!(loop_count, 3);
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoopMode {
+    /// Stop at the end.
+    #[default]
+    Once,
+    /// Wrap around to the other end forever.
+    Loop,
+    /// Wrap around until the play range is played `n` times in total.
+    Repeat(u32),
+    /// Bounce back and forth forever by flipping the sign of the `time_scale`.
+    PingPong,
 }
 
 // SEQUENCE ORDERING FUNCTIONS
@@ -94,10 +510,13 @@ pub trait MultiSeqOrd {
     fn chain(self) -> Sequence;
     /// Run all [`Sequence`]s concurrently and wait for all of them to finish.
     fn all(self) -> Sequence;
-    /// Run all [`Sequence`]s concurrently and wait for any of them to finish.
+    /// Run all [`Sequence`]s concurrently and wait for any of them to finish,
+    /// cutting the rest of them at that point.
     fn any(self) -> Sequence;
     /// Run one [`Sequence`] after another with a fixed delay time.
     fn flow(self, delay: f32) -> Sequence;
+    /// Run all [`Sequence`]s concurrently with start offsets spread by a [`Stagger`].
+    fn stagger(self, stagger: &Stagger) -> Sequence;
 }
 
 impl MultiSeqOrd for &[Sequence] {
@@ -116,184 +535,573 @@ impl MultiSeqOrd for &[Sequence] {
     fn flow(self, t: f32) -> Sequence {
         flow(t, self)
     }
+
+    fn stagger(self, stagger: &Stagger) -> Sequence {
+        stagger::stagger(stagger, self)
+    }
 }
 
 pub trait SingleSeqOrd {
     /// Run a [`Sequence`] after a fixed delay time.
     fn delay(self, t: f32) -> Sequence;
+    /// Run a [`Sequence`] `n` times one after another.
+    fn repeat(self, n: usize) -> Sequence;
+    /// Run a [`Sequence`] backwards in time.
+    fn reversed(self) -> Sequence;
+    /// Stretch the timing of a [`Sequence`] to fit a new duration, see [`stretch`].
+    fn with_duration(self, duration: f32) -> Sequence;
+    /// Keep only the time range of a [`Sequence`] between `start` and `end`.
+    fn trim(self, start: f32, end: f32) -> Sequence;
+    /// Remap the time across the whole [`Sequence`] with an easing function.
+    fn with_ease<E>(self, ease_fn: E) -> Sequence
+    where
+        E: Fn(f32) -> f32 + Send + Sync + 'static;
+    /// Remap the time across the whole [`Sequence`] with a plain [`EaseFn`] (fast path).
+    fn with_ease_fn(self, ease_fn: EaseFn) -> Sequence;
 }
 
 impl SingleSeqOrd for Sequence {
     fn delay(self, t: f32) -> Sequence {
         delay(t, self)
     }
+
+    fn repeat(self, n: usize) -> Sequence {
+        repeat(n, self)
+    }
+
+    fn reversed(self) -> Sequence {
+        reverse(self)
+    }
+
+    fn with_duration(self, duration: f32) -> Sequence {
+        stretch(duration, self)
+    }
+
+    fn trim(self, start: f32, end: f32) -> Sequence {
+        trim(start, end, self)
+    }
+
+    fn with_ease<E>(self, ease_fn: E) -> Sequence
+    where
+        E: Fn(f32) -> f32 + Send + Sync + 'static,
+    {
+        warp(ease_fn, self)
+    }
+
+    fn with_ease_fn(self, ease_fn: EaseFn) -> Sequence {
+        warp_ease(Ease::from_fn(ease_fn), self)
+    }
 }
 
 /// Run one [`Sequence`] after another.
 pub fn chain(sequences: &[Sequence]) -> Sequence {
-    let mut final_sequence = Sequence::default();
+    let mut action_metas = Vec::new();
     let mut chain_duration = 0.0;
 
+    let mut offsets = Vec::with_capacity(sequences.len());
+
     for sequence in sequences {
         for action_meta in &sequence.action_metas {
-            final_sequence
-                .action_metas
-                .push(action_meta.with_start_time(action_meta.start_time + chain_duration));
+            action_metas.push(action_meta.shifted(chain_duration));
         }
 
+        offsets.push(chain_duration);
         chain_duration += sequence.duration;
     }
 
-    final_sequence.duration = chain_duration;
-    final_sequence
+    let mut chain_sequence = Sequence::new(action_metas, chain_duration);
+    for (sequence, offset) in sequences.iter().zip(offsets) {
+        chain_sequence.extend_markers(sequence, offset);
+        chain_sequence.extend_clips(sequence, offset);
+    }
+
+    chain_sequence
 }
 
 /// Run all [`Sequence`]s concurrently and wait for all of them to finish.
 pub fn all(sequences: &[Sequence]) -> Sequence {
-    let mut final_sequence = Sequence::default();
+    let mut action_metas = Vec::new();
     let mut max_duration = 0.0;
 
     for sequence in sequences {
-        for action_meta in &sequence.action_metas {
-            final_sequence.action_metas.push(*action_meta);
-        }
+        action_metas.extend_from_slice(&sequence.action_metas);
 
         max_duration = f32::max(max_duration, sequence.duration);
     }
 
-    final_sequence.duration = max_duration;
-    final_sequence
+    let mut all_sequence = Sequence::new(action_metas, max_duration);
+    for sequence in sequences {
+        all_sequence.extend_markers(sequence, 0.0);
+        all_sequence.extend_clips(sequence, 0.0);
+    }
+
+    all_sequence
 }
 
 /// Run all [`Sequence`]s concurrently and wait for any of them to finish.
+///
+/// The first [`Sequence`] to finish wins the race: actions of the other [`Sequence`]s
+/// are cut at that point and actions that have not started by then are cancelled.
+///
+/// # Example
+///
+/// ```rust
+/// use motiongfx_core::prelude::*;
+///
+/// let sequence = [
+///     Sequence::default().delay(3.0),
+///     Sequence::default().delay(1.0).mark("winner"),
+///     Sequence::default().delay(2.0).mark("loser"),
+/// ]
+/// .any();
+///
+/// assert_eq!(sequence.duration(), 1.0);
+/// assert_eq!(sequence.marker_time("winner"), Some(1.0));
+/// assert_eq!(sequence.marker_time("loser"), None);
+/// ```
 pub fn any(sequences: &[Sequence]) -> Sequence {
-    let mut final_sequence = Sequence::default();
-    let mut min_duration = 0.0;
-
-    for action_grp in sequences {
-        for action_meta in &action_grp.action_metas {
-            final_sequence.action_metas.push(*action_meta);
+    let Some(min_duration) = sequences
+        .iter()
+        .map(|sequence| sequence.duration)
+        .min_by(f32::total_cmp)
+    else {
+        return Sequence::default();
+    };
+
+    // Clips cannot be cut at runtime.
+    let baked_sequences;
+    let sequences = match sequences
+        .iter()
+        .any(|sequence| sequence.clips.is_empty() == false)
+    {
+        true => {
+            baked_sequences = sequences
+                .iter()
+                .cloned()
+                .map(Sequence::baked)
+                .collect::<Vec<_>>();
+            &baked_sequences[..]
         }
+        false => sequences,
+    };
+
+    let mut action_metas = Vec::new();
+    for sequence in sequences {
+        action_metas.extend(
+            sequence
+                .action_metas
+                .iter()
+                // Cancel actions that only start after the race is over.
+                .filter(|action_meta| {
+                    action_meta.start_time < min_duration
+                        || (action_meta.duration <= 0.0 && action_meta.start_time <= min_duration)
+                })
+                .map(|action_meta| action_meta.trimmed(0.0, min_duration)),
+        );
+    }
 
-        min_duration = f32::min(min_duration, action_grp.duration);
+    let mut any_sequence = Sequence::new(action_metas, min_duration);
+    for sequence in sequences {
+        any_sequence.markers.extend(
+            sequence
+                .markers
+                .iter()
+                .filter(|marker| marker.time <= min_duration)
+                .cloned(),
+        );
     }
 
-    final_sequence.duration = min_duration;
-    final_sequence
+    any_sequence
 }
 
 /// Run one [`Sequence`] after another with a fixed delay time.
 pub fn flow(t: f32, sequences: &[Sequence]) -> Sequence {
-    let mut final_sequence = Sequence::default();
+    let mut action_metas = Vec::new();
     let mut flow_duration = 0.0;
     let mut final_duration = 0.0;
 
+    let mut offsets = Vec::with_capacity(sequences.len());
+
     for sequence in sequences {
         for action_meta in &sequence.action_metas {
-            final_sequence
-                .action_metas
-                .push(action_meta.with_start_time(action_meta.start_time + flow_duration));
+            action_metas.push(action_meta.shifted(flow_duration));
         }
 
+        offsets.push(flow_duration);
         flow_duration += t;
         final_duration = f32::max(final_duration, flow_duration + sequence.duration);
     }
 
-    final_sequence.duration = final_duration;
-    final_sequence
+    let mut flow_sequence = Sequence::new(action_metas, final_duration);
+    for (sequence, offset) in sequences.iter().zip(offsets) {
+        flow_sequence.extend_markers(sequence, offset);
+        flow_sequence.extend_clips(sequence, offset);
+    }
+
+    flow_sequence
 }
 
 /// Run a [`Sequence`] after a fixed delay time.
 pub fn delay(t: f32, sequence: Sequence) -> Sequence {
-    let mut final_sequence = Sequence::default();
+    let action_metas = sequence
+        .action_metas
+        .iter()
+        .map(|action_meta| action_meta.shifted(t))
+        .collect();
 
-    for action_meta in &sequence.action_metas {
-        final_sequence
-            .action_metas
-            .push(action_meta.with_start_time(action_meta.start_time + t));
-    }
+    let mut delay_sequence = Sequence::new(action_metas, sequence.duration + t);
+    delay_sequence.extend_markers(&sequence, t);
+    delay_sequence.extend_clips(&sequence, t);
 
-    final_sequence.duration = sequence.duration + t;
-    final_sequence
+    delay_sequence
 }
 
-/// System for mutating the [`Component`] related [`Action`]s that are inside the [`Sequence`].
-pub fn update_component<U, T>(
-    mut q_components: Query<&mut U>,
-    q_actions: Query<&'static Action<T, U>>,
-    q_sequences: Query<(&Sequence, &SequenceController)>,
-) where
-    T: Send + Sync + 'static,
-    U: Component,
+/// Run a [`Sequence`] `n` times one after another.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::{prelude::*, MotionGfxCorePlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, MotionGfxCorePlugin))
+///     .register_motion::<Transform, f32>();
+///
+/// let mut transform = Transform::default();
+/// let id = app.world_mut().spawn(transform).id();
+///
+/// let mut commands = app.world_mut().commands();
+/// let sequence = commands
+///     .play_motion(
+///         act!((id, Transform), start = { transform }.translation.x, end = 1.0,)
+///             .with_ease_fn(ease::linear)
+///             .animate(1.0),
+///     )
+///     .repeat(3);
+/// assert_eq!(sequence.duration(), 3.0);
+///
+/// let sequence_id = commands.spawn(SequenceBundle::from_sequence(sequence)).id();
+/// app.world_mut().flush();
+///
+/// for (time, x) in [(0.5, 0.5), (2.25, 0.25), (1.75, 0.75), (3.0, 1.0), (0.0, 0.0)] {
+///     let mut controller = app.world_mut().get_mut::<SequenceController>(sequence_id).unwrap();
+///     controller.seek(time);
+///     app.update();
+///
+///     let transform = app.world().get::<Transform>(id).unwrap();
+///     assert!(f32::abs(transform.translation.x - x) < 1e-5);
+/// }
+/// ```
+pub fn repeat(n: usize, sequence: Sequence) -> Sequence {
+    chain(&vec![sequence; n])
+}
+
+/// Run a [`Sequence`] backwards in time.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::{prelude::*, MotionGfxCorePlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, MotionGfxCorePlugin))
+///     .register_motion::<Transform, f32>();
+///
+/// let mut transform = Transform::default();
+/// let id = app.world_mut().spawn(transform).id();
+///
+/// let mut commands = app.world_mut().commands();
+/// let sequence = commands
+///     .play_motion(
+///         act!((id, Transform), start = { transform }.translation.x, end = 1.0,)
+///             .with_ease_fn(ease::linear)
+///             .animate(1.0),
+///     )
+///     .mark("end")
+///     .delay(1.0)
+///     .reversed();
+/// let sequence_id = commands.spawn(SequenceBundle::from_sequence(sequence)).id();
+/// app.world_mut().flush();
+///
+/// for (time, x) in [(0.25, 0.75), (1.0, 0.0), (2.0, 0.0), (0.0, 1.0)] {
+///     let mut controller = app.world_mut().get_mut::<SequenceController>(sequence_id).unwrap();
+///     controller.seek(time);
+///     app.update();
+///
+///     let transform = app.world().get::<Transform>(id).unwrap();
+///     assert!(f32::abs(transform.translation.x - x) < 1e-5);
+/// }
+/// ```
+pub fn reverse(sequence: Sequence) -> Sequence {
+    let sequence = sequence.baked();
+    let duration = sequence.duration;
+    let action_metas = sequence
+        .action_metas
+        .iter()
+        .map(|action_meta| action_meta.reversed(duration))
+        .collect();
+
+    Sequence::new(action_metas, duration).with_markers_of(&sequence, |time| Some(duration - time))
+}
+
+/// Stretch the timing of a [`Sequence`] to fit a new `duration`.
+///
+/// # Panics
+///
+/// Panics if the [`Sequence`] has a duration and the new `duration` is not positive and finite,
+/// as the timing cannot be scaled to (or from) nothing.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::{prelude::*, MotionGfxCorePlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, MotionGfxCorePlugin))
+///     .register_motion::<Transform, f32>();
+///
+/// let mut transform = Transform::default();
+/// let id = app.world_mut().spawn(transform).id();
+///
+/// let mut commands = app.world_mut().commands();
+/// let sequence = [1.0, 3.0]
+///     .map(|x| {
+///         commands.play_motion(
+///             act!((id, Transform), start = { transform }.translation.x, end = x,)
+///                 .with_ease_fn(ease::linear)
+///                 .animate(1.0),
+///         )
+///     })
+///     .chain()
+///     .with_duration(4.0);
+/// assert_eq!(sequence.duration(), 4.0);
+///
+/// let sequence_id = commands.spawn(SequenceBundle::from_sequence(sequence)).id();
+/// app.world_mut().flush();
+///
+/// for (time, x) in [(1.0, 0.5), (3.0, 2.0), (4.0, 3.0), (0.5, 0.25)] {
+///     let mut controller = app.world_mut().get_mut::<SequenceController>(sequence_id).unwrap();
+///     controller.seek(time);
+///     app.update();
+///
+///     let transform = app.world().get::<Transform>(id).unwrap();
+///     assert!(f32::abs(transform.translation.x - x) < 1e-5);
+/// }
+/// ```
+pub fn stretch(duration: f32, sequence: Sequence) -> Sequence {
+    let scale = match sequence.duration > 0.0 {
+        true => {
+            assert!(
+                duration > 0.0 && duration.is_finite(),
+                "A `Sequence` can only be stretched to a positive and finite duration, got {duration}."
+            );
+            duration / sequence.duration
+        }
+        false => 1.0,
+    };
+    let action_metas = sequence
+        .action_metas
+        .iter()
+        .map(|action_meta| action_meta.stretched(scale))
+        .collect();
+
+    let mut stretch_sequence =
+        Sequence::new(action_metas, duration).with_markers_of(&sequence, |time| Some(time * scale));
+    stretch_sequence.clips = sequence
+        .clips
+        .iter()
+        .map(|clip| clip.stretched(scale))
+        .collect();
+
+    stretch_sequence
+}
+
+/// Keep only the time range of a [`Sequence`] between `start` and `end`.
+///
+/// Actions that end before `start` hold their end values, while actions that start after `end`
+/// hold their start values.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::{prelude::*, MotionGfxCorePlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, MotionGfxCorePlugin))
+///     .register_motion::<Transform, f32>();
+///
+/// let mut transform = Transform::default();
+/// let id = app.world_mut().spawn(transform).id();
+///
+/// let mut commands = app.world_mut().commands();
+/// let sequence = commands
+///     .play_motion(
+///         act!((id, Transform), start = { transform }.translation.x, end = 2.0,)
+///             .with_ease_fn(ease::linear)
+///             .animate(2.0),
+///     )
+///     .trim(0.5, 1.5);
+/// assert_eq!(sequence.duration(), 1.0);
+///
+/// let sequence_id = commands.spawn(SequenceBundle::from_sequence(sequence)).id();
+/// app.world_mut().flush();
+///
+/// for (time, x) in [(0.0, 0.5), (0.5, 1.0), (1.0, 1.5), (0.25, 0.75)] {
+///     let mut controller = app.world_mut().get_mut::<SequenceController>(sequence_id).unwrap();
+///     controller.seek(time);
+///     app.update();
+///
+///     let transform = app.world().get::<Transform>(id).unwrap();
+///     assert!(f32::abs(transform.translation.x - x) < 1e-5);
+/// }
+/// ```
+pub fn trim(start: f32, end: f32, sequence: Sequence) -> Sequence {
+    let sequence = sequence.baked();
+    let start = f32::clamp(start, 0.0, sequence.duration);
+    let end = f32::clamp(end, start, sequence.duration);
+
+    let action_metas = sequence
+        .action_metas
+        .iter()
+        .map(|action_meta| action_meta.trimmed(start, end))
+        .collect();
+
+    Sequence::new(action_metas, end - start).with_markers_of(&sequence, |time| {
+        (start..=end).contains(&time).then_some(time - start)
+    })
+}
+
+/// Remap the time across the whole [`Sequence`] with an easing function.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::{prelude::*, MotionGfxCorePlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, MotionGfxCorePlugin))
+///     .register_motion::<Transform, f32>();
+///
+/// let mut transform = Transform::default();
+/// let id = app.world_mut().spawn(transform).id();
+///
+/// let mut commands = app.world_mut().commands();
+/// let sequence = [
+///     commands.play_motion(
+///         act!((id, Transform), start = { transform }.translation.x, end = 1.0,)
+///             .with_ease_fn(ease::linear)
+///             .animate(1.0),
+///     ),
+///     commands.play_motion(
+///         act!((id, Transform), start = { transform }.translation.x, end = 2.0,)
+///             .with_ease_fn(ease::linear)
+///             .animate(1.0),
+///     ),
+/// ]
+/// .chain()
+/// .with_ease_fn(ease::quad::ease_in);
+/// let sequence_id = commands.spawn(SequenceBundle::from_sequence(sequence)).id();
+/// app.world_mut().flush();
+///
+/// for time in [0.5, 1.5, 2.0, 1.0, 0.0] {
+///     let mut controller = app.world_mut().get_mut::<SequenceController>(sequence_id).unwrap();
+///     controller.target_time = time;
+///     app.update();
+///
+///     let transform = app.world().get::<Transform>(id).unwrap();
+///     let x = ease::quad::ease_in(time / 2.0) * 2.0;
+///     assert!(f32::abs(transform.translation.x - x) < 1e-5);
+/// }
+/// ```
+pub fn warp<E>(ease_fn: E, sequence: Sequence) -> Sequence
+where
+    E: Fn(f32) -> f32 + Send + Sync + 'static,
 {
-    for (sequence, sequence_controller) in q_sequences.iter() {
-        if let Some(action) = generate_action_iter(&q_actions, sequence, sequence_controller) {
-            for (action, action_meta) in action {
-                // Get component to mutate based on action id
-                let Ok(mut component) = q_components.get_mut(action.target_id) else {
-                    continue;
-                };
-
-                let mut unit_time = (sequence_controller.target_time - action_meta.start_time)
-                    / action_meta.duration;
-
-                // In case of division by 0.0
-                if f32::is_nan(unit_time) {
-                    unit_time = 0.0;
-                }
+    warp_ease(Ease::new(ease_fn), sequence)
+}
 
-                unit_time = f32::clamp(unit_time, 0.0, 1.0);
-                // Calculate unit time using ease function
-                unit_time = (action.ease_fn)(unit_time);
+/// Remap the time across the whole [`Sequence`] with an [`Ease`].
+pub fn warp_ease(ease_fn: Ease, sequence: Sequence) -> Sequence {
+    let sequence = sequence.baked();
+    let duration = sequence.duration;
+    let action_metas = sequence
+        .action_metas
+        .iter()
+        .map(|action_meta| action_meta.eased(&ease_fn, duration))
+        .collect();
+
+    Sequence::new(action_metas, duration).with_markers_of(&sequence, |time| {
+        Some(first_time(&ease_fn, duration, |local_time| {
+            local_time >= time
+        }))
+    })
+}
+
+/// System for applying all [`Action`]s that are inside every [`Sequence`] in a single pass.
+///
+/// Each [`Action`] is dispatched to the type-erased applier registered in the [`MotionRegistry`].
+///
+/// [`Action`]: crate::action::Action
+pub(crate) fn update_sequences(
+    world: &mut World,
+    q_sequences: &mut QueryState<(&Sequence, &SequenceController)>,
+    mut updates: Local<Vec<(TypeId, Entity, f32, f32)>>,
+) {
+    for (sequence, sequence_controller) in q_sequences.iter(world) {
+        push_updates(sequence, sequence_controller, &mut updates);
+    }
+
+    if updates.is_empty() {
+        return;
+    }
 
-                // Mutate the component using interpolate function
-                let field = (action.get_field_fn)(&mut component);
-                *field = (action.interp_fn)(&action.start, &action.end, unit_time);
+    world.resource_scope(|world, registry: Mut<MotionRegistry>| {
+        for (type_id, action_id, prev_unit_time, unit_time) in updates.drain(..) {
+            if let Some(apply) = registry.get(&type_id) {
+                apply(world, action_id, prev_unit_time, unit_time);
             }
         }
+    });
+}
+
+/// System that registers [`Action<T, U>`] on a [`Component`] in the [`MotionRegistry`].
+///
+/// [`Action`]s are applied by [`MotionGfxCorePlugin`](crate::MotionGfxCorePlugin) in a single pass,
+/// so adding this system no longer applies anything by itself.
+///
+/// [`Action<T, U>`]: crate::action::Action
+#[deprecated(note = "register the action with `App::register_motion::<U, T>()` instead")]
+pub fn update_component<U, T>(mut registry: ResMut<MotionRegistry>)
+where
+    T: Send + Sync + 'static,
+    U: Component,
+{
+    // Check before registering to avoid triggering change detection.
+    if registry.contains::<U, T>() == false {
+        registry.register_component::<U, T>();
     }
 }
 
-/// System for mutating the [`Asset`] related [`Action`]s that are inside the [`Sequence`].
-pub fn update_asset<U, T>(
-    q_handles: Query<&Handle<U>>,
-    mut assets: ResMut<Assets<U>>,
-    q_actions: Query<&'static Action<T, U>>,
-    q_sequences: Query<(&Sequence, &SequenceController)>,
-) where
+/// System that registers [`Action<T, U>`] on an [`Asset`] in the [`MotionRegistry`].
+///
+/// [`Action`]s are applied by [`MotionGfxCorePlugin`](crate::MotionGfxCorePlugin) in a single pass,
+/// so adding this system no longer applies anything by itself.
+///
+/// [`Action<T, U>`]: crate::action::Action
+#[deprecated(note = "register the action with `App::register_asset_motion::<U, T>()` instead")]
+pub fn update_asset<U, T>(mut registry: ResMut<MotionRegistry>)
+where
     T: Send + Sync + 'static,
     U: Asset,
 {
-    for (sequence, sequence_controller) in q_sequences.iter() {
-        if let Some(action) = generate_action_iter(&q_actions, sequence, sequence_controller) {
-            for (action, action_meta) in action {
-                // Get handle based on action id
-                let Ok(handle) = q_handles.get(action.target_id) else {
-                    continue;
-                };
-
-                // Get asset to mutate based on the handle id
-                let Some(asset) = assets.get_mut(handle) else {
-                    continue;
-                };
-
-                let mut unit_time = (sequence_controller.target_time - action_meta.start_time)
-                    / action_meta.duration;
-
-                // In case of division by 0.0
-                if f32::is_nan(unit_time) {
-                    unit_time = 0.0;
-                }
-
-                unit_time = f32::clamp(unit_time, 0.0, 1.0);
-                // Calculate unit time using ease function
-                unit_time = (action.ease_fn)(unit_time);
-
-                // Mutate the component using interpolate function
-                let field = (action.get_field_fn)(asset);
-                *field = (action.interp_fn)(&action.start, &action.end, unit_time);
-            }
-        }
+    // Check before registering to avoid triggering change detection.
+    if registry.contains::<U, T>() == false {
+        registry.register_asset::<U, T>();
     }
 }
 
@@ -303,31 +1111,203 @@ pub(crate) fn sequence_controller(mut q_sequences: Query<(&Sequence, &mut Sequen
         sequence_controller.target_time =
             f32::clamp(sequence_controller.target_time, 0.0, sequence.duration());
         sequence_controller.curr_time = sequence_controller.target_time;
+        sequence_controller.resample = false;
+        sequence_controller.wrap = None;
     }
 }
 
-/// Update [`SequenceController`] based on `time_scale` of [`SequencePlayer`].
+/// Resolve the target marker of [`SequenceController`]s into a [seek](SequenceController::seek).
+pub(crate) fn seek_markers(mut q_sequences: Query<(&Sequence, &mut SequenceController)>) {
+    for (sequence, mut sequence_controller) in q_sequences.iter_mut() {
+        // Check before taking to avoid triggering change detection.
+        if sequence_controller.target_marker.is_none() {
+            continue;
+        }
+
+        let Some(name) = sequence_controller.target_marker.take() else {
+            continue;
+        };
+
+        match sequence.marker_time(&name) {
+            Some(time) => sequence_controller.seek(time),
+            None => warn!("Marker `{name}` does not exist in the sequence."),
+        }
+    }
+}
+
+/// Update [`SequenceController`] based on `time_scale`, `loop_mode` and `fixed_fps` of [`SequencePlayer`].
+///
+/// Wrapping around [seeks](SequenceController::seek) to the wrapped time
+/// so that every action is re-applied from scratch, while events are still fired
+/// for the points crossed on both sides of the wrap.
+#[allow(clippy::type_complexity)]
 pub(crate) fn sequence_player(
-    mut q_sequences: Query<(&Sequence, &mut SequenceController, &SequencePlayer)>,
+    mut commands: Commands,
+    mut q_sequences: Query<
+        (
+            Entity,
+            &Sequence,
+            &mut SequenceController,
+            &mut SequencePlayer,
+            Option<&mut SequencePlayerState>,
+        ),
+        Without<PausePlayback>,
+    >,
     time: Res<Time>,
 ) {
-    for (sequence, mut sequence_controller, sequence_player) in q_sequences.iter_mut() {
-        sequence_controller.target_time = f32::clamp(
-            sequence_controller.target_time + time.delta_seconds() * sequence_player.time_scale,
-            0.0,
-            sequence.duration(),
+    for (id, sequence, mut sequence_controller, mut sequence_player, player_state) in
+        q_sequences.iter_mut()
+    {
+        let mut state = player_state.as_deref().copied().unwrap_or_default();
+        let (start, end) = sequence_player.clamped_range(sequence.duration());
+        let time_scale = sequence_player.time_scale;
+        let target_time = match sequence_player.fixed_fps {
+            // Step from the nearest frame so that errors do not accumulate.
+            Some(fps) if fps > 0 && f32::round(time_scale) != 0.0 => {
+                let fps = fps as f32;
+                (f32::round(sequence_controller.target_time * fps) + f32::round(time_scale)) / fps
+            }
+            Some(_) => sequence_controller.target_time,
+            None => sequence_controller.target_time + time.delta_seconds() * time_scale,
+        };
+
+        // Only loop when moving out of the play range in the direction of play.
+        let is_overshoot =
+            (time_scale > 0.0 && target_time > end) || (time_scale < 0.0 && target_time < start);
+
+        if is_overshoot == false || end - start <= f32::EPSILON {
+            sequence_controller.target_time = f32::clamp(target_time, start, end);
+        } else {
+            match sequence_player.loop_mode {
+                LoopMode::Once => {
+                    sequence_controller.target_time = f32::clamp(target_time, start, end);
+                }
+                LoopMode::Repeat(n) if state.loop_count + 1 >= n => {
+                    sequence_controller.target_time = f32::clamp(target_time, start, end);
+                }
+                LoopMode::Loop | LoopMode::Repeat(_) => {
+                    state.loop_count += 1;
+                    sequence_controller
+                        .seek(start + f32::rem_euclid(target_time - start, end - start));
+                    sequence_controller.wrap = match time_scale > 0.0 {
+                        true => Some((end, start)),
+                        false => Some((start, end)),
+                    };
+                }
+                LoopMode::PingPong => {
+                    state.loop_count += 1;
+                    sequence_player.time_scale = -time_scale;
+                    sequence_controller.target_time = f32::clamp(target_time, start, end);
+                }
+            }
+        }
+
+        if let Some(fps) = sequence_player.fixed_fps {
+            state.frame = f32::round(sequence_controller.target_time * fps as f32) as u32;
+        }
+
+        match player_state {
+            Some(mut player_state) => {
+                player_state.set_if_neq(state);
+            }
+            None => {
+                commands.entity(id).insert(state);
+            }
+        }
+    }
+}
+
+/// Generate the [`ActionMeta`]s that need to be applied for the current update
+/// together with their previous and current (un-eased) unit time.
+///
+/// Performs a full evaluation when [`SequenceController::resample`] is requested,
+/// otherwise, only the [`ActionMeta`]s that overlap the time window between `curr_time`
+/// and `target_time` are returned.
+fn generate_updates<'a>(
+    sequence: &'a Sequence,
+    sequence_controller: &'a SequenceController,
+    mut push: impl FnMut(&'a ActionMeta, f32, f32),
+) {
+    if sequence_controller.resample {
+        generate_samples(sequence, sequence_controller, push);
+        return;
+    }
+
+    let Some(action_iter) = generate_action_iter(sequence, sequence_controller) else {
+        return;
+    };
+
+    for action_meta in action_iter {
+        push(
+            action_meta,
+            action_meta.unit_time(sequence_controller.curr_time),
+            action_meta.unit_time(sequence_controller.target_time),
         );
     }
 }
 
-fn generate_action_iter<'a, T, U>(
-    q_actions: &'a Query<&'static Action<T, U>>,
+/// Generate every [`ActionMeta`] in the [`Sequence`] with the unit time it holds at `target_time`.
+///
+/// Actions that have not started yet are applied first (latest to earliest),
+/// followed by all started actions (earliest to latest).
+/// This way, every field ends up with the value of the last override action that touched it,
+/// or the start value of the first override action that will touch it.
+///
+/// Additive actions replace their contribution from the time of the override action
+/// that last wrote the field in this pass, as the value of that override action already
+/// includes everything before it. Fields without one hold the contributions at `curr_time`,
+/// which are rewound (including the ones of actions that have not started yet),
+/// leaving the value that the field had before the [`Sequence`] started.
+fn generate_samples<'a>(
     sequence: &'a Sequence,
     sequence_controller: &'a SequenceController,
-) -> Option<impl std::iter::Iterator<Item = (&'a Action<T, U>, &'a ActionMeta)>>
-where
-    T: Send + Sync + 'static,
-{
+    mut push: impl FnMut(&'a ActionMeta, f32, f32),
+) {
+    let time = f32::clamp(sequence_controller.target_time, 0.0, sequence.duration());
+
+    let pending = sequence
+        .action_metas
+        .iter()
+        .rev()
+        .filter(|action_meta| action_meta.start_time > time);
+    let started = sequence
+        .action_metas
+        .iter()
+        .filter(|action_meta| action_meta.start_time <= time);
+
+    // Field paths that have been overwritten so far with the start time of the override action, per target.
+    let mut overwritten = HashMap::<_, Vec<_>>::new();
+
+    for action_meta in pending.chain(started) {
+        let target = (action_meta.target_id(), action_meta.target_type_id());
+        let unit_time = action_meta.unit_time(time);
+
+        if action_meta.is_additive() == false {
+            overwritten
+                .entry(target)
+                .or_default()
+                .push((action_meta.field_path(), action_meta.start_time));
+            push(action_meta, unit_time, unit_time);
+            continue;
+        }
+
+        let overwrite_time = overwritten.get(&target).and_then(|field_paths| {
+            field_paths
+                .iter()
+                .rev()
+                .find(|(field_path, _)| is_field_overlap(*field_path, action_meta.field_path()))
+                .map(|(_, start_time)| *start_time)
+        });
+        let prev_unit_time =
+            action_meta.unit_time(overwrite_time.unwrap_or(sequence_controller.curr_time));
+        push(action_meta, prev_unit_time, unit_time);
+    }
+}
+
+pub(crate) fn generate_action_iter<'a>(
+    sequence: &'a Sequence,
+    sequence_controller: &'a SequenceController,
+) -> Option<impl std::iter::Iterator<Item = &'a ActionMeta>> {
     // Do not perform any actions if there are no changes to the timeline timings
     // or there are no actions at all.
     if sequence_controller.curr_time == sequence_controller.target_time
@@ -349,58 +1329,27 @@ where
         sequence_controller.target_time,
     );
 
-    let mut start_index = 0;
-    let mut end_index = sequence.action_metas.len() - 1;
-
-    // Swap direction if needed
-    if direction == -1 {
-        start_index = end_index;
-        end_index = 0;
-    }
-
-    let mut action_index = start_index;
+    // Only visit `ActionMeta`s that overlap with the time range,
+    // in the direction that the timeline is going towards.
+    let mut action_indices = sequence.index.query(
+        &sequence.action_metas,
+        timeline_start,
+        timeline_end,
+        direction == -1,
+    );
 
     Some(std::iter::from_fn(move || {
-        // Loop through `Action`s in the direction that the timeline is going towards.
-        loop {
-            if action_index == (end_index as isize + direction) as usize {
-                return None;
-            }
-
-            let action_meta = &sequence.action_metas[action_index];
-            let action_id = action_meta.id();
+        let action_meta = &sequence.action_metas[action_indices.next()?];
 
-            let slide_direction = isize::signum(
-                sequence_controller.target_slide_index as isize - action_meta.slide_index as isize,
-            );
-
-            // Continue only when slide direction matches or is 0
-            if slide_direction != 0 && slide_direction != direction {
-                return None;
-            }
-
-            action_index = (action_index as isize + direction) as usize;
-
-            let is_time_overlap = time_range_overlap(
-                action_meta.start_time,
-                action_meta.end_time(),
-                timeline_start,
-                timeline_end,
-            );
-            // Ignore if `ActionMeta` not in range
-            if is_time_overlap == false {
-                continue;
-            }
+        let slide_direction = isize::signum(
+            sequence_controller.target_slide_index as isize - action_meta.slide_index as isize,
+        );
 
-            // Ignore if `Action` does not exists
-            if let Ok(action) = q_actions.get(action_id) {
-                return Some((action, action_meta));
-            }
+        // Continue only when slide direction matches or is 0
+        if slide_direction != 0 && slide_direction != direction {
+            return None;
         }
-    }))
-}
 
-/// Calculate if 2 time range (in float) overlaps.
-fn time_range_overlap(a_begin: f32, a_end: f32, b_begin: f32, b_end: f32) -> bool {
-    a_begin <= b_end && b_begin <= a_end
+        Some(action_meta)
+    }))
 }
@@ -0,0 +1,125 @@
+use smallvec::SmallVec;
+
+use crate::action::ActionMeta;
+
+/// A static interval tree for looking up [`ActionMeta`]s that overlap a time range.
+///
+/// The [`ActionMeta`]s are expected to be sorted by their start time. The sorted slice is
+/// treated as an implicit balanced binary tree where the node of range `[lo, hi)` sits at
+/// `(lo + hi) / 2`, so only the maximum end time of each subtree needs to be stored.
+/// Queries cost `O(log n + k)` where `k` is the number of overlapping [`ActionMeta`]s.
+#[derive(Default, Clone)]
+pub(crate) struct ActionIndex {
+    /// Maximum end time of the subtree rooted at each node.
+    max_end_times: Vec<f32>,
+}
+
+impl ActionIndex {
+    /// Builds the index from [`ActionMeta`]s that are sorted by their start time.
+    pub fn new(action_metas: &[ActionMeta]) -> Self {
+        let mut max_end_times = vec![f32::NEG_INFINITY; action_metas.len()];
+        Self::build(action_metas, &mut max_end_times, 0, action_metas.len());
+
+        Self { max_end_times }
+    }
+
+    fn build(action_metas: &[ActionMeta], max_end_times: &mut [f32], lo: usize, hi: usize) -> f32 {
+        if lo >= hi {
+            return f32::NEG_INFINITY;
+        }
+
+        let mid = (lo + hi) / 2;
+        let left_max = Self::build(action_metas, max_end_times, lo, mid);
+        let right_max = Self::build(action_metas, max_end_times, mid + 1, hi);
+
+        let max_end_time = f32::max(action_metas[mid].end_time(), f32::max(left_max, right_max));
+        max_end_times[mid] = max_end_time;
+        max_end_time
+    }
+
+    /// Iterate through the indices of all [`ActionMeta`]s that overlap `[begin, end]`
+    /// in ascending order of start time (or descending order if `reverse` is true).
+    pub fn query<'a>(
+        &'a self,
+        action_metas: &'a [ActionMeta],
+        begin: f32,
+        end: f32,
+        reverse: bool,
+    ) -> ActionIndexIter<'a> {
+        let mut stack = SmallVec::new();
+        stack.push(IndexNode::Range(0, action_metas.len()));
+
+        ActionIndexIter {
+            action_metas,
+            max_end_times: &self.max_end_times,
+            begin,
+            end,
+            reverse,
+            stack,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum IndexNode {
+    /// A subtree that has not been visited yet.
+    Range(usize, usize),
+    /// A single node whose start time is known to be within range.
+    Item(usize),
+}
+
+/// In-order traversal of an [`ActionIndex`] that skips subtrees outside of the query range.
+pub(crate) struct ActionIndexIter<'a> {
+    action_metas: &'a [ActionMeta],
+    max_end_times: &'a [f32],
+    begin: f32,
+    end: f32,
+    reverse: bool,
+    stack: SmallVec<[IndexNode; 64]>,
+}
+
+impl Iterator for ActionIndexIter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.stack.pop() {
+            match node {
+                IndexNode::Range(lo, hi) => {
+                    if lo >= hi {
+                        continue;
+                    }
+
+                    let mid = (lo + hi) / 2;
+                    // Nothing in this subtree ends after the query begins.
+                    if self.max_end_times[mid] < self.begin {
+                        continue;
+                    }
+
+                    // Everything from `mid` onwards starts after the query ends.
+                    if self.action_metas[mid].start_time > self.end {
+                        self.stack.push(IndexNode::Range(lo, mid));
+                        continue;
+                    }
+
+                    // Push in reverse order of visit.
+                    if self.reverse {
+                        self.stack.push(IndexNode::Range(lo, mid));
+                        self.stack.push(IndexNode::Item(mid));
+                        self.stack.push(IndexNode::Range(mid + 1, hi));
+                    } else {
+                        self.stack.push(IndexNode::Range(mid + 1, hi));
+                        self.stack.push(IndexNode::Item(mid));
+                        self.stack.push(IndexNode::Range(lo, mid));
+                    }
+                }
+                IndexNode::Item(index) => {
+                    if self.action_metas[index].end_time() >= self.begin {
+                        return Some(index);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
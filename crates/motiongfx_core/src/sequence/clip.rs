@@ -0,0 +1,383 @@
+use std::any::TypeId;
+
+use bevy::prelude::*;
+
+use crate::action::ActionMeta;
+
+use super::{
+    any, chain, delay, generate_updates, reverse, stretch, trim, Sequence, SequenceController,
+};
+
+/// A [`Sequence`] embedded inside another [`Sequence`] with its own clock.
+///
+/// Instead of being flattened into the parent, the parent drives the local time of the clip
+/// through its `offset`, `time_scale` and `loops`, so the clip can be retimed
+/// with [`Sequence::retime_clip`] without rebuilding anything.
+///
+/// Reversing, trimming, easing or racing (with [`any`](super::any)) a [`Sequence`] bakes its clips into plain actions.
+/// Markers, [`SequenceEvent`](crate::event::SequenceEvent)s and conflict detection
+/// only cover the actions of the parent.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::{prelude::*, MotionGfxCorePlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, MotionGfxCorePlugin))
+///     .register_motion::<Transform, f32>();
+///
+/// let mut transform = Transform::default();
+/// let id = app.world_mut().spawn(transform).id();
+///
+/// let mut commands = app.world_mut().commands();
+/// let bounce = commands.play_motion(
+///     act!((id, Transform), start = { transform }.translation.x, end = 1.0,)
+///         .with_ease_fn(ease::linear)
+///         .animate(1.0),
+/// );
+///
+/// // Plays twice as fast, forth and back, starting after 1 second.
+/// let clip = SequenceClip::new(bounce)
+///     .with_name("bounce")
+///     .with_offset(1.0)
+///     .with_time_scale(2.0)
+///     .with_loops(2.0)
+///     .with_ping_pong();
+/// let sequence = Sequence::from(clip);
+/// assert_eq!(sequence.duration(), 2.0);
+///
+/// let sequence_id = commands.spawn(SequenceBundle::from_sequence(sequence)).id();
+/// app.world_mut().flush();
+///
+/// let mut check = |app: &mut App, time: f32, x: f32| {
+///     let mut controller = app.world_mut().get_mut::<SequenceController>(sequence_id).unwrap();
+///     controller.target_time = time;
+///     app.update();
+///
+///     let transform = app.world().get::<Transform>(id).unwrap();
+///     assert!(f32::abs(transform.translation.x - x) < 1e-5);
+/// };
+///
+/// check(&mut app, 0.5, 0.0);
+/// check(&mut app, 1.25, 0.5);
+/// check(&mut app, 1.6, 0.8);
+/// check(&mut app, 2.0, 0.0);
+///
+/// // Slow the clip down to real time.
+/// app.world_mut()
+///     .get_mut::<Sequence>(sequence_id)
+///     .unwrap()
+///     .retime_clip("bounce", |clip| clip.set_time_scale(1.0));
+/// assert_eq!(app.world().get::<Sequence>(sequence_id).unwrap().duration(), 3.0);
+///
+/// check(&mut app, 2.5, 0.5);
+///
+/// // Speeding the clip up shortens the parent again.
+/// app.world_mut()
+///     .get_mut::<Sequence>(sequence_id)
+///     .unwrap()
+///     .retime_clip("bounce", |clip| clip.set_time_scale(4.0));
+/// assert_eq!(app.world().get::<Sequence>(sequence_id).unwrap().duration(), 1.5);
+/// ```
+#[derive(Clone)]
+pub struct SequenceClip {
+    pub sequence: Sequence,
+    /// Name for looking up the clip inside its parent.
+    pub name: Option<String>,
+    /// Start time of the clip inside the parent.
+    pub offset: f32,
+    /// Speed of the clip's clock relative to the parent's clock (positive and finite).
+    time_scale: f32,
+    /// Number of times the [`Sequence`] is played (non-negative and finite),
+    /// fractional counts stop part way through.
+    loops: f32,
+    /// Play every other loop backwards.
+    pub ping_pong: bool,
+}
+
+impl SequenceClip {
+    pub fn new(sequence: Sequence) -> Self {
+        Self {
+            sequence,
+            name: None,
+            offset: 0.0,
+            time_scale: 1.0,
+            loops: 1.0,
+            ping_pong: false,
+        }
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn with_offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set the speed of the clip's clock relative to the parent's clock.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `time_scale` is not positive and finite,
+    /// as the clip would have no (or a negative) duration.
+    pub fn with_time_scale(mut self, time_scale: f32) -> Self {
+        self.set_time_scale(time_scale);
+        self
+    }
+
+    /// Set the number of times the [`Sequence`] is played.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `loops` is negative or not finite.
+    pub fn with_loops(mut self, loops: f32) -> Self {
+        self.set_loops(loops);
+        self
+    }
+
+    pub fn with_ping_pong(mut self) -> Self {
+        self.ping_pong = true;
+        self
+    }
+
+    /// Speed of the clip's clock relative to the parent's clock.
+    #[inline]
+    pub fn time_scale(&self) -> f32 {
+        self.time_scale
+    }
+
+    /// Same as [`Self::with_time_scale`] in place.
+    pub fn set_time_scale(&mut self, time_scale: f32) {
+        assert_time_scale(time_scale);
+        self.time_scale = time_scale;
+    }
+
+    /// Number of times the [`Sequence`] is played.
+    #[inline]
+    pub fn loops(&self) -> f32 {
+        self.loops
+    }
+
+    /// Same as [`Self::with_loops`] in place.
+    pub fn set_loops(&mut self, loops: f32) {
+        assert!(
+            loops >= 0.0 && loops.is_finite(),
+            "The loop count of a `SequenceClip` must be non-negative and finite, got {loops}."
+        );
+        self.loops = loops;
+    }
+
+    /// Duration of the clip inside the parent.
+    #[inline]
+    pub fn duration(&self) -> f32 {
+        self.loops * self.sequence.duration() / self.time_scale
+    }
+
+    /// End time of the clip inside the parent.
+    #[inline]
+    pub fn end_time(&self) -> f32 {
+        self.offset + self.duration()
+    }
+
+    /// Loop index and local time of the clip at the parent `time`.
+    pub fn local_time(&self, time: f32) -> (u32, f32) {
+        let duration = self.sequence.duration();
+        // Instant clips jump to their end as soon as they start.
+        if duration <= 0.0 {
+            return (u32::from(time >= self.offset), 0.0);
+        }
+
+        let elapsed = f32::clamp(
+            (time - self.offset) * self.time_scale,
+            0.0,
+            self.loops * duration,
+        );
+
+        // The end of a loop belongs to that loop.
+        let iteration = f32::max(f32::ceil(elapsed / duration) - 1.0, 0.0);
+        let local_time = elapsed - iteration * duration;
+        let iteration = iteration as u32;
+
+        match self.ping_pong && iteration % 2 == 1 {
+            true => (iteration, duration - local_time),
+            false => (iteration, local_time),
+        }
+    }
+
+    pub(super) fn shifted(&self, offset: f32) -> Self {
+        Self {
+            offset: self.offset + offset,
+            ..self.clone()
+        }
+    }
+
+    pub(super) fn stretched(&self, scale: f32) -> Self {
+        let mut clip = Self {
+            offset: self.offset * scale,
+            ..self.clone()
+        };
+        clip.set_time_scale(self.time_scale / scale);
+        clip
+    }
+
+    /// Controller that drives the clip from the controller of its parent.
+    ///
+    /// Moving across loops jumps in local time, so the clip is resampled.
+    fn local_controller(&self, sequence_controller: &SequenceController) -> SequenceController {
+        let (curr_iteration, curr_time) = self.local_time(sequence_controller.curr_time);
+        let (target_iteration, target_time) = self.local_time(sequence_controller.target_time);
+
+        SequenceController {
+            curr_time,
+            target_time,
+            target_slide_index: sequence_controller.target_slide_index,
+            resample: sequence_controller.resample || curr_iteration != target_iteration,
+            target_marker: None,
+            wrap: None,
+        }
+    }
+
+    /// Flatten the clip into a [`Sequence`] of plain actions.
+    fn baked(&self) -> Sequence {
+        let mut sequence = self.sequence.clone().baked();
+        sequence.markers.clear();
+
+        let iteration = stretch(sequence.duration() / self.time_scale, sequence);
+        let iterations = (0..f32::max(f32::ceil(self.loops), 1.0) as usize)
+            .map(|i| match self.ping_pong && i % 2 == 1 {
+                true => reverse(iteration.clone()),
+                false => iteration.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        // Race against an empty sequence to cut the last loop short.
+        let clip_sequence = any(&[chain(&iterations), Sequence::empty(self.duration())]);
+        match self.offset >= 0.0 {
+            true => delay(self.offset, clip_sequence),
+            false => trim(-self.offset, clip_sequence.duration(), clip_sequence),
+        }
+    }
+}
+
+impl From<SequenceClip> for Sequence {
+    fn from(clip: SequenceClip) -> Self {
+        Self {
+            duration: f32::max(clip.end_time(), 0.0),
+            clips: vec![clip],
+            ..default()
+        }
+    }
+}
+
+impl Sequence {
+    /// Iterate over the clips embedded directly inside this [`Sequence`].
+    pub fn clips(&self) -> impl Iterator<Item = &SequenceClip> {
+        self.clips.iter()
+    }
+
+    /// Retime the first clip with the given `name` in place, returns false if there is none.
+    ///
+    /// The [`Sequence`] is extended if the clip ends later than before,
+    /// or shortened down to the end of its other contents if the clip ended it before.
+    /// [Resample](SequenceController::resample) to apply the new timing to the current time right away.
+    ///
+    /// Only the duration of the parent changes. Actions and clips that were chained after the clip
+    /// keep their start times, so a longer clip overlaps them and a shorter one leaves a gap.
+    pub fn retime_clip(&mut self, name: &str, retime: impl FnOnce(&mut SequenceClip)) -> bool {
+        let Some(clip) = self
+            .clips
+            .iter_mut()
+            .find(|clip| clip.name.as_deref() == Some(name))
+        else {
+            return false;
+        };
+
+        let prev_end_time = clip.end_time();
+        retime(clip);
+        let end_time = clip.end_time();
+
+        self.duration = match prev_end_time >= self.duration {
+            true => f32::max(self.content_end_time(), 0.0),
+            false => f32::max(self.duration, end_time),
+        };
+        true
+    }
+
+    /// Latest end time of the actions and clips embedded directly inside this [`Sequence`].
+    fn content_end_time(&self) -> f32 {
+        let action_end_times = self.action_metas.iter().map(ActionMeta::end_time);
+        let clip_end_times = self.clips.iter().map(SequenceClip::end_time);
+
+        action_end_times
+            .chain(clip_end_times)
+            .fold(f32::NEG_INFINITY, f32::max)
+    }
+
+    /// Append the clips of a [`Sequence`] shifted by `offset`.
+    pub(crate) fn extend_clips(&mut self, sequence: &Sequence, offset: f32) {
+        self.clips
+            .extend(sequence.clips.iter().map(|clip| clip.shifted(offset)));
+    }
+
+    /// Flatten all clips into plain actions.
+    pub(crate) fn baked(self) -> Sequence {
+        if self.clips.is_empty() {
+            return self;
+        }
+
+        let mut action_metas = self.action_metas.clone();
+        for clip in &self.clips {
+            action_metas.extend(clip.baked().action_metas);
+        }
+
+        Sequence::new(action_metas, self.duration).with_markers_of(&self, Some)
+    }
+
+    /// Visit every [`ActionMeta`] of this [`Sequence`] and its clips.
+    pub(crate) fn for_each_action_meta(&self, f: &mut impl FnMut(&ActionMeta)) {
+        self.action_metas.iter().for_each(&mut *f);
+        for clip in &self.clips {
+            clip.sequence.for_each_action_meta(f);
+        }
+    }
+}
+
+fn assert_time_scale(time_scale: f32) {
+    assert!(
+        time_scale > 0.0 && time_scale.is_finite(),
+        "The time scale of a `SequenceClip` must be positive and finite, got {time_scale}."
+    );
+}
+
+/// Push the updates of a [`Sequence`] followed by the updates of its clips.
+pub(super) fn push_updates(
+    sequence: &Sequence,
+    sequence_controller: &SequenceController,
+    updates: &mut Vec<(TypeId, Entity, f32, f32)>,
+) {
+    generate_updates(
+        sequence,
+        sequence_controller,
+        |action_meta, prev_unit_time, unit_time| {
+            updates.push((
+                action_meta.action_type_id(),
+                action_meta.id(),
+                prev_unit_time,
+                unit_time,
+            ));
+        },
+    );
+
+    for clip in &sequence.clips {
+        push_updates(
+            &clip.sequence,
+            &clip.local_controller(sequence_controller),
+            updates,
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use std::sync::Arc;
+
+use crate::ease::Ease;
+
+/// Affine mapping from a time range onto a (un-eased) unit time range.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct TimeClip {
+    pub start_time: f32,
+    pub duration: f32,
+    /// Unit time at the start of the time range.
+    pub unit_start: f32,
+    /// Unit time at the end of the time range.
+    pub unit_end: f32,
+}
+
+impl TimeClip {
+    pub fn new(start_time: f32, duration: f32) -> Self {
+        Self {
+            start_time,
+            duration,
+            unit_start: 0.0,
+            unit_end: 1.0,
+        }
+    }
+
+    #[inline]
+    pub fn end_time(&self) -> f32 {
+        self.start_time + self.duration
+    }
+
+    /// Calculate the unit time at a given `time`, clamped within the unit time range.
+    #[inline]
+    pub fn unit_time(&self, time: f32) -> f32 {
+        let t = (time - self.start_time) / self.duration;
+
+        // In case of division by 0.0
+        let t = match f32::is_nan(t) {
+            true => 0.0,
+            false => f32::clamp(t, 0.0, 1.0),
+        };
+
+        self.unit_start + (self.unit_end - self.unit_start) * t
+    }
+
+    pub fn shifted(mut self, offset: f32) -> Self {
+        self.start_time += offset;
+        self
+    }
+
+    pub fn stretched(mut self, scale: f32) -> Self {
+        self.start_time *= scale;
+        self.duration *= scale;
+        self
+    }
+
+    /// Reverse in time within `[0, duration]`.
+    pub fn reversed(self, duration: f32) -> Self {
+        Self {
+            start_time: duration - self.end_time(),
+            duration: self.duration,
+            unit_start: self.unit_end,
+            unit_end: self.unit_start,
+        }
+    }
+
+    /// Keep only the time range within `[start, end]` and shift it back by `start`.
+    pub fn trimmed(self, start: f32, end: f32) -> Self {
+        // Instant clips cannot be cut, only moved within the range.
+        if self.duration <= 0.0 {
+            return match (self.start_time < start, self.start_time > end) {
+                (true, _) => Self {
+                    start_time: 0.0,
+                    duration: 0.0,
+                    unit_start: self.unit_end,
+                    unit_end: self.unit_end,
+                },
+                (_, true) => Self {
+                    start_time: end - start,
+                    duration: 0.0,
+                    unit_start: self.unit_start,
+                    unit_end: self.unit_start,
+                },
+                _ => self.shifted(-start),
+            };
+        }
+
+        let start_time = f32::clamp(self.start_time, start, end);
+        let end_time = f32::clamp(self.end_time(), start, end);
+
+        Self {
+            start_time: start_time - start,
+            duration: end_time - start_time,
+            unit_start: self.unit_time(start_time),
+            unit_end: self.unit_time(end_time),
+        }
+    }
+}
+
+/// Eases the time of a composite [`Sequence`](super::Sequence) before it is mapped onto the next [`TimeClip`].
+pub(crate) struct TimeWarp {
+    pub ease_fn: Ease,
+    /// Duration of the composite.
+    pub duration: f32,
+    /// Clip inside the local time of the composite.
+    pub clip: TimeClip,
+    /// Nested warp of an inner composite.
+    pub inner: Option<Arc<TimeWarp>>,
+}
+
+impl TimeWarp {
+    /// Local time of the composite at a given unit time.
+    #[inline]
+    pub fn local_time(&self, unit_time: f32) -> f32 {
+        self.ease_fn.ease(unit_time) * self.duration
+    }
+}
+
+/// Find the earliest time within `[0, duration]` where `predicate` holds
+/// on the eased local time of a composite.
+///
+/// Returns `duration` if `predicate` never holds.
+pub(crate) fn first_time(ease_fn: &Ease, duration: f32, predicate: impl Fn(f32) -> bool) -> f32 {
+    crossing_time(ease_fn, duration, predicate, false).unwrap_or(duration)
+}
+
+/// Find the latest time within `[0, duration]` where `predicate` holds
+/// on the eased local time of a composite.
+///
+/// Returns 0.0 if `predicate` never holds.
+pub(crate) fn last_time(ease_fn: &Ease, duration: f32, predicate: impl Fn(f32) -> bool) -> f32 {
+    crossing_time(ease_fn, duration, predicate, true).unwrap_or(0.0)
+}
+
+fn crossing_time(
+    ease_fn: &Ease,
+    duration: f32,
+    predicate: impl Fn(f32) -> bool,
+    reverse: bool,
+) -> Option<f32> {
+    const SAMPLES: usize = 64;
+    const BISECTIONS: usize = 24;
+
+    if duration <= 0.0 {
+        return Some(0.0);
+    }
+
+    let time = |sample: usize| duration * sample as f32 / SAMPLES as f32;
+    let holds = |time: f32| predicate(ease_fn.ease(time / duration) * duration);
+
+    // Coarse search for the first sample that holds, followed by bisection with the previous sample.
+    let (mut outside, mut inside) = match reverse {
+        false => {
+            let sample = (0..=SAMPLES).find(|&sample| holds(time(sample)))?;
+            if sample == 0 {
+                return Some(0.0);
+            }
+            (time(sample - 1), time(sample))
+        }
+        true => {
+            let sample = (0..=SAMPLES).rev().find(|&sample| holds(time(sample)))?;
+            if sample == SAMPLES {
+                return Some(duration);
+            }
+            (time(sample + 1), time(sample))
+        }
+    };
+
+    for _ in 0..BISECTIONS {
+        let mid = (outside + inside) * 0.5;
+        match holds(mid) {
+            true => inside = mid,
+            false => outside = mid,
+        }
+    }
+
+    Some(inside)
+}
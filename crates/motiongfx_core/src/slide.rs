@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::prelude::{MultiSeqOrd, Sequence, SequenceController};
+use crate::prelude::{MultiSeqOrd, PausePlayback, Sequence, SequenceController};
 
 #[derive(Bundle, Default)]
 pub struct SlideBundle {
@@ -53,6 +53,11 @@ impl SlideController {
         self.utime_scale = f32::abs(time_scale);
     }
 
+    #[inline]
+    pub fn time_scale(&self) -> f32 {
+        self.utime_scale
+    }
+
     #[inline]
     pub fn slide_count(&self) -> usize {
         self.start_times.len().saturating_sub(1)
@@ -109,7 +114,7 @@ pub fn create_slide(mut sequences: Vec<Sequence>) -> SlideBundle {
 }
 
 pub(crate) fn slide_controller(
-    mut q_slides: Query<(&mut SlideController, &mut SequenceController)>,
+    mut q_slides: Query<(&mut SlideController, &mut SequenceController), Without<PausePlayback>>,
     time: Res<Time>,
 ) {
     for (mut slide_controller, mut sequence_controller) in q_slides.iter_mut() {
@@ -0,0 +1,192 @@
+use bevy::prelude::*;
+
+use crate::{
+    ease::{self, Ease, EaseFn},
+    sequence::Sequence,
+};
+
+/// Spreads the start times of multiple [`Sequence`]s.
+///
+/// Each [`Sequence`] is ranked between 0.0 and 1.0 based on the [order](Self::from_center),
+/// and the rank is eased and scaled by the `duration` of the stagger to get its start offset.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::prelude::*;
+///
+/// let stagger = Stagger::new(1.0).from_center();
+/// assert_eq!(stagger.offsets(5), vec![1.0, 0.5, 0.0, 0.5, 1.0]);
+///
+/// let stagger = Stagger::new(1.0)
+///     .from_point([Vec3::X, Vec3::Y * 2.0, Vec3::ZERO], Vec3::ZERO)
+///     .with_ease_fn(ease::quad::ease_in);
+/// assert_eq!(stagger.offsets(3), vec![0.25, 1.0, 0.0]);
+///
+/// let sequence = [Sequence::default().delay(1.0), Sequence::default().delay(1.0)]
+///     .stagger(&Stagger::new(0.5));
+/// assert_eq!(sequence.duration(), 1.5);
+/// ```
+#[derive(Clone)]
+pub struct Stagger {
+    /// Offset between the earliest and the latest start time.
+    duration: f32,
+    /// Easing function applied on the rank of each [`Sequence`].
+    ease_fn: Ease,
+    order: StaggerOrder,
+}
+
+#[derive(Clone)]
+enum StaggerOrder {
+    /// In slice order.
+    Index,
+    /// From the middle of the slice outwards.
+    Center,
+    /// By distance (per [`Sequence`]).
+    Distance(Vec<f32>),
+    /// Random order with a seed.
+    Random(u64),
+}
+
+impl Stagger {
+    /// Creates a new [`Stagger`] in slice order where the last [`Sequence`] starts `duration` after the first.
+    pub fn new(duration: f32) -> Self {
+        Self {
+            duration,
+            ease_fn: Ease::Fn(ease::linear),
+            order: StaggerOrder::Index,
+        }
+    }
+
+    /// Overwrite the [easing function](Ease) that spreads the offsets.
+    pub fn with_ease<E>(mut self, ease_fn: E) -> Self
+    where
+        E: Fn(f32) -> f32 + Send + Sync + 'static,
+    {
+        self.ease_fn = Ease::new(ease_fn);
+        self
+    }
+
+    /// Overwrite the [easing function](Ease) with a plain [`EaseFn`] (fast path).
+    pub fn with_ease_fn(mut self, ease_fn: EaseFn) -> Self {
+        self.ease_fn = Ease::from_fn(ease_fn);
+        self
+    }
+
+    /// Start from the middle of the slice outwards.
+    pub fn from_center(mut self) -> Self {
+        self.order = StaggerOrder::Center;
+        self
+    }
+
+    /// Start from the nearest position to `point`, with one position per [`Sequence`].
+    pub fn from_point(mut self, positions: impl IntoIterator<Item = Vec3>, point: Vec3) -> Self {
+        self.order = StaggerOrder::Distance(
+            positions
+                .into_iter()
+                .map(|position| position.distance(point))
+                .collect(),
+        );
+        self
+    }
+
+    /// Start in a random order that is fixed by the `seed`.
+    pub fn random(mut self, seed: u64) -> Self {
+        self.order = StaggerOrder::Random(seed);
+        self
+    }
+
+    /// Calculate the start offsets of `count` [`Sequence`]s.
+    pub fn offsets(&self, count: usize) -> Vec<f32> {
+        let last = count.saturating_sub(1).max(1) as f32;
+
+        let ranks: Vec<f32> = match &self.order {
+            StaggerOrder::Index => (0..count).map(|i| i as f32 / last).collect(),
+            StaggerOrder::Center => {
+                let center = last * 0.5;
+                (0..count)
+                    .map(|i| f32::abs(i as f32 - center) / f32::max(center, f32::EPSILON))
+                    .collect()
+            }
+            StaggerOrder::Distance(distances) => {
+                let min = distances.iter().copied().fold(f32::INFINITY, f32::min);
+                let max = distances.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+                let range = f32::max(max - min, f32::EPSILON);
+
+                // Missing positions start last.
+                (0..count)
+                    .map(|i| {
+                        distances
+                            .get(i)
+                            .map_or(1.0, |distance| (distance - min) / range)
+                    })
+                    .collect()
+            }
+            StaggerOrder::Random(seed) => {
+                let mut order = (0..count).collect::<Vec<_>>();
+                let mut rng = SplitMix64(*seed);
+                // Fisher-Yates shuffle
+                for i in (1..count).rev() {
+                    order.swap(i, rng.next_below(i + 1));
+                }
+
+                let mut ranks = vec![0.0; count];
+                for (rank, i) in order.into_iter().enumerate() {
+                    ranks[i] = rank as f32 / last;
+                }
+                ranks
+            }
+        };
+
+        ranks
+            .into_iter()
+            .map(|rank| self.ease_fn.ease(rank) * self.duration)
+            .collect()
+    }
+}
+
+/// Run all [`Sequence`]s concurrently with start offsets spread by a [`Stagger`].
+pub fn stagger(stagger: &Stagger, sequences: &[Sequence]) -> Sequence {
+    let offsets = stagger.offsets(sequences.len());
+
+    let mut action_metas = Vec::new();
+    let mut final_duration = 0.0;
+
+    for (sequence, &offset) in sequences.iter().zip(&offsets) {
+        action_metas.extend(
+            sequence
+                .action_metas
+                .iter()
+                .map(|action_meta| action_meta.shifted(offset)),
+        );
+
+        final_duration = f32::max(final_duration, offset + sequence.duration());
+    }
+
+    let mut stagger_sequence = Sequence::new(action_metas, final_duration);
+    for (sequence, offset) in sequences.iter().zip(offsets) {
+        stagger_sequence.extend_markers(sequence, offset);
+        stagger_sequence.extend_clips(sequence, offset);
+    }
+
+    stagger_sequence
+}
+
+/// Small seeded random number generator (SplitMix64).
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Random number within `[0, bound)`.
+    fn next_below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
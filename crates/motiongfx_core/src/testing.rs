@@ -0,0 +1,826 @@
+//! Headless utilities for checking animated values without opening a window.
+//!
+//! Only available with the `testing` feature enabled.
+
+use std::{any::TypeId, fmt, ops::Range, path::Path, time::Duration};
+
+use bevy::{prelude::*, time::TimeUpdateStrategy, utils::HashMap};
+
+use crate::{
+    action::{Action, ActionMeta},
+    conflict::is_field_overlap,
+    event::PlaybackDirection,
+    sequence::{PausePlayback, Sequence, SequenceBundle, SequenceController},
+    slide::{create_slide, SlideController},
+    MotionGfxCorePlugin,
+};
+
+/// Environment variable that makes [`assert_snapshot`] record snapshots instead of comparing them.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "MOTIONGFX_UPDATE_SNAPSHOTS";
+
+/// A minimal [`App`] with [`MotionGfxCorePlugin`] for playing a [`Sequence`] in tests.
+///
+/// Every update advances [`Time`] by a fixed frame time (1/60 seconds by default),
+/// so stepping through [`SequencePlayer`](crate::sequence::SequencePlayer)s and slides is deterministic.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::{
+///     prelude::*,
+///     testing::{self, SequenceHarness},
+/// };
+///
+/// let mut harness = SequenceHarness::new();
+/// harness.app_mut().register_motion::<Transform, f32>();
+///
+/// let mut transform = Transform::default();
+/// let id = harness.spawn(transform);
+///
+/// let sequence = harness
+///     .commands()
+///     .play_motion(
+///         act!((id, Transform), start = { transform }.translation.x, end = 1.0,)
+///             .with_ease_fn(ease::linear)
+///             .animate(1.0),
+///     )
+///     .reversed();
+/// harness.play(sequence);
+///
+/// let samples = harness.sample([0.0, 0.5, 1.0, 0.25], |world| {
+///     world.get::<Transform>(id).unwrap().translation.x
+/// });
+/// for (x, expected) in samples.values().into_iter().zip([1.0, 0.5, 0.0, 0.75]) {
+///     assert!(f32::abs(x - expected) < 1e-5);
+/// }
+///
+/// let path = std::env::temp_dir().join("motiongfx_harness_example.snap");
+/// let _ = std::fs::remove_file(&path);
+/// // Missing snapshots fail unless recording is requested.
+/// let missing = std::panic::catch_unwind(|| samples.assert_snapshot(&path));
+/// assert!(missing.is_err());
+///
+/// std::env::set_var(testing::UPDATE_SNAPSHOTS_ENV, "1");
+/// samples.assert_snapshot(&path);
+/// std::env::remove_var(testing::UPDATE_SNAPSHOTS_ENV);
+/// samples.assert_snapshot(&path);
+/// ```
+pub struct SequenceHarness {
+    app: App,
+    /// Entity of the [`Sequence`] that is being played.
+    sequence_id: Option<Entity>,
+}
+
+impl SequenceHarness {
+    pub fn new() -> Self {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, MotionGfxCorePlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+                1.0 / 60.0,
+            )));
+
+        Self {
+            app,
+            sequence_id: None,
+        }
+    }
+
+    /// Overwrite the time that passes on every update.
+    pub fn with_frame_time(mut self, frame_time: f32) -> Self {
+        self.app
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+                frame_time,
+            )));
+        self
+    }
+
+    pub fn app(&self) -> &App {
+        &self.app
+    }
+
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    pub fn world(&self) -> &World {
+        self.app.world()
+    }
+
+    pub fn world_mut(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+
+    /// [`Commands`] for building [`Sequence`]s, applied on the next update or [`play`](Self::play).
+    pub fn commands(&mut self) -> Commands<'_, '_> {
+        self.app.world_mut().commands()
+    }
+
+    pub fn spawn(&mut self, bundle: impl Bundle) -> Entity {
+        self.app.world_mut().spawn(bundle).id()
+    }
+
+    pub fn add_asset<A: Asset>(&mut self, asset: A) -> Handle<A> {
+        self.app
+            .world_mut()
+            .get_resource_or_insert_with(Assets::<A>::default)
+            .add(asset)
+    }
+
+    /// Spawn a [`Sequence`] and make it the target of seeking, scrubbing and sampling.
+    pub fn play(&mut self, sequence: Sequence) -> Entity {
+        self.play_bundle(SequenceBundle::from_sequence(sequence))
+    }
+
+    /// Spawn [`Sequence`]s as slides and make them the target of seeking, scrubbing and sampling.
+    pub fn play_slides(&mut self, sequences: Vec<Sequence>) -> Entity {
+        self.play_bundle(create_slide(sequences))
+    }
+
+    /// Spawn a bundle containing a [`SequenceController`] and make it the target of seeking, scrubbing and sampling.
+    ///
+    /// The bundle is [seeked](Self::seek) to the start so that every animated value starts from a known state.
+    pub fn play_bundle(&mut self, bundle: impl Bundle) -> Entity {
+        let world = self.app.world_mut();
+        world.flush();
+
+        let sequence_id = world.spawn(bundle).id();
+        self.sequence_id = Some(sequence_id);
+        self.seek(0.0);
+        sequence_id
+    }
+
+    /// Entity of the [`Sequence`] that is being played.
+    ///
+    /// # Panics
+    ///
+    /// Panics if nothing has been played yet.
+    pub fn sequence_id(&self) -> Entity {
+        self.sequence_id
+            .expect("Play a sequence before controlling it.")
+    }
+
+    pub fn sequence_controller_mut(&mut self) -> Mut<'_, SequenceController> {
+        let sequence_id = self.sequence_id();
+        self.app
+            .world_mut()
+            .get_mut::<SequenceController>(sequence_id)
+            .expect("Played entity has no `SequenceController`.")
+    }
+
+    pub fn slide_controller_mut(&mut self) -> Mut<'_, SlideController> {
+        let sequence_id = self.sequence_id();
+        self.app
+            .world_mut()
+            .get_mut::<SlideController>(sequence_id)
+            .expect("Played entity has no `SlideController`.")
+    }
+
+    /// [Seek](SequenceController::seek) to `time` and update once.
+    pub fn seek(&mut self, time: f32) -> &mut Self {
+        self.sequence_controller_mut().seek(time);
+        self.app.update();
+        self
+    }
+
+    /// Move the target time to `time` and update once, like regular playback does.
+    pub fn scrub(&mut self, time: f32) -> &mut Self {
+        self.sequence_controller_mut().target_time = time;
+        self.app.update();
+        self
+    }
+
+    /// Update `count` times.
+    pub fn step(&mut self, count: usize) -> &mut Self {
+        for _ in 0..count {
+            self.app.update();
+        }
+        self
+    }
+
+    /// Clone a component of an entity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entity does not have the component.
+    pub fn component<C: Component + Clone>(&self, id: Entity) -> C {
+        self.app
+            .world()
+            .get::<C>(id)
+            .unwrap_or_else(|| panic!("{id} has no `{}`.", std::any::type_name::<C>()))
+            .clone()
+    }
+
+    /// Clone an asset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the asset does not exist.
+    pub fn asset<A: Asset + Clone>(&self, handle: &Handle<A>) -> A {
+        self.app
+            .world()
+            .get_resource::<Assets<A>>()
+            .and_then(|assets| assets.get(handle))
+            .unwrap_or_else(|| panic!("`{}` does not exist.", std::any::type_name::<A>()))
+            .clone()
+    }
+
+    /// [Scrub](Self::scrub) through `times` in order and read a value after each update.
+    pub fn sample<T>(
+        &mut self,
+        times: impl IntoIterator<Item = f32>,
+        mut read: impl FnMut(&World) -> T,
+    ) -> Samples<T> {
+        Samples(
+            times
+                .into_iter()
+                .map(|time| (time, read(self.scrub(time).world())))
+                .collect(),
+        )
+    }
+
+    /// [Seek](Self::seek) to each of the `times` and read a value after each update.
+    pub fn sample_seek<T>(
+        &mut self,
+        times: impl IntoIterator<Item = f32>,
+        mut read: impl FnMut(&World) -> T,
+    ) -> Samples<T> {
+        Samples(
+            times
+                .into_iter()
+                .map(|time| (time, read(self.seek(time).world())))
+                .collect(),
+        )
+    }
+}
+
+impl SequenceHarness {
+    /// Play the [`Sequence`] forwards and backwards with every step size of a [`ReversibilityCheck`].
+    ///
+    /// Whenever a step reaches or crosses the start or end of an [`Action`], the fields are compared
+    /// against reference values taken by [seeking](SequenceController::seek) to the same time.
+    /// Fields that are in the middle of an [`Action`] are left out until they settle.
+    /// The reported [`Action`] is the last one in the direction of playback that wrote to the diverging field
+    /// since it last matched, as that is the one holding the field.
+    ///
+    /// Playback steps through slides as if all of them were unlocked, and the [`Sequence`] is paused
+    /// with [`PausePlayback`] during the check. The original time is restored afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use motiongfx_core::{
+    ///     prelude::*,
+    ///     testing::{ReversibilityCheck, SequenceHarness},
+    /// };
+    ///
+    /// let mut harness = SequenceHarness::new();
+    /// harness.app_mut().register_motion::<Transform, f32>();
+    ///
+    /// let mut transform = Transform::default();
+    /// let id = harness.spawn(transform);
+    ///
+    /// let mut commands = harness.commands();
+    /// let slides = vec![
+    ///     commands.play_motion(
+    ///         act!((id, Transform), start = { transform }.translation.x, end = 1.0,).animate(1.0),
+    ///     ),
+    ///     commands.play_motion(
+    ///         act!((id, Transform), start = { transform }.scale.y, end = 2.0,).animate(0.5),
+    ///     ),
+    /// ];
+    /// harness.play_slides(slides);
+    ///
+    /// let check = ReversibilityCheck::new([1.0 / 60.0, 0.3, 10.0]).component::<Transform, f32>();
+    /// assert_eq!(harness.check_reversibility(&check), Ok(()));
+    /// ```
+    ///
+    /// The [`Action`] that breaks reversibility is reported even when others write to the same field.
+    ///
+    /// ```rust
+    /// use std::sync::atomic::{AtomicU32, Ordering};
+    ///
+    /// use bevy::prelude::*;
+    /// use motiongfx_core::{
+    ///     prelude::*,
+    ///     testing::{ReversibilityCheck, SequenceHarness},
+    /// };
+    ///
+    /// // Drifts a little further every time it is applied.
+    /// static APPLIED: AtomicU32 = AtomicU32::new(0);
+    /// fn drift(start: &f32, end: &f32, t: f32) -> f32 {
+    ///     let drift = APPLIED.fetch_add(1, Ordering::Relaxed) as f32 * 1e-3;
+    ///     f32::lerp(*start, *end, t) + drift
+    /// }
+    ///
+    /// let mut harness = SequenceHarness::new();
+    /// harness.app_mut().register_motion::<Transform, f32>();
+    ///
+    /// let mut transform = Transform::default();
+    /// let id = harness.spawn(transform);
+    ///
+    /// let sequence = harness
+    ///     .commands()
+    ///     .add_motion(
+    ///         act!((id, Transform), start = { transform }.translation.x, end = 1.0,).animate(1.0),
+    ///     )
+    ///     .add_motion(
+    ///         act!((id, Transform), start = { transform }.translation.x, end = 2.0,)
+    ///             .with_interp(drift)
+    ///             .animate(1.0),
+    ///     )
+    ///     .chain();
+    /// harness.play(sequence);
+    ///
+    /// let check = ReversibilityCheck::new([0.3]).component::<Transform, f32>();
+    /// let error = harness.check_reversibility(&check).unwrap_err();
+    /// assert_eq!(error.direction, PlaybackDirection::Forward);
+    /// assert_eq!(error.time_range, 1.0..2.0);
+    /// ```
+    pub fn check_reversibility(
+        &mut self,
+        check: &ReversibilityCheck,
+    ) -> Result<(), ReversibilityError> {
+        let sequence_id = self.sequence_id();
+        // Bake the clips so that every action has its time range inside the played sequence.
+        let sequence = self.component::<Sequence>(sequence_id).baked();
+        let duration = sequence.duration();
+        let action_metas = sequence.action_metas;
+        let last_slide_index = action_metas
+            .iter()
+            .map(|action_meta| action_meta.slide_index)
+            .max()
+            .unwrap_or_default();
+
+        // Pause anything that moves the time by itself.
+        let mut entity = self.app.world_mut().entity_mut(sequence_id);
+        let is_paused = entity.contains::<PausePlayback>();
+        entity.insert(PausePlayback);
+        let (time, slide_index) = {
+            let sequence_controller = entity.get::<SequenceController>().unwrap();
+            (
+                sequence_controller.target_time,
+                sequence_controller.target_slide_index,
+            )
+        };
+
+        let mut result = Ok(());
+        for &step_size in &check.step_sizes {
+            let forward = Playback::new(
+                &action_metas,
+                0.0,
+                duration,
+                step_size,
+                PlaybackDirection::Forward,
+            );
+            let backward = Playback::new(
+                &action_metas,
+                duration,
+                0.0,
+                step_size,
+                PlaybackDirection::Backward,
+            );
+
+            // Take every reference value up front, as seeking in between would disturb the playback.
+            let forward_expected = self.probe_checkpoints(check, &action_metas, &forward);
+            let backward_expected = self.probe_checkpoints(check, &action_metas, &backward);
+
+            self.seek(0.0);
+            self.sequence_controller_mut().target_slide_index = last_slide_index;
+            result = self.play_through(check, &action_metas, &forward, &forward_expected);
+            if result.is_err() {
+                break;
+            }
+
+            self.sequence_controller_mut().target_slide_index = 0;
+            result = self.play_through(check, &action_metas, &backward, &backward_expected);
+            if result.is_err() {
+                break;
+            }
+        }
+
+        // Restore the original state.
+        self.sequence_controller_mut().target_slide_index = slide_index;
+        self.seek(time);
+        if is_paused == false {
+            self.app
+                .world_mut()
+                .entity_mut(sequence_id)
+                .remove::<PausePlayback>();
+        }
+
+        result
+    }
+
+    /// Read the fields at every checkpoint of a [`Playback`] by seeking.
+    fn probe_checkpoints(
+        &mut self,
+        check: &ReversibilityCheck,
+        action_metas: &[ActionMeta],
+        playback: &Playback,
+    ) -> Vec<Vec<Option<String>>> {
+        playback
+            .checkpoints()
+            .map(|time| self.seek(time).probe(check, action_metas))
+            .collect()
+    }
+
+    /// Scrub through a [`Playback`], comparing the settled fields at every checkpoint against `expected` values.
+    fn play_through(
+        &mut self,
+        check: &ReversibilityCheck,
+        action_metas: &[ActionMeta],
+        playback: &Playback,
+        expected: &[Vec<Option<String>>],
+    ) -> Result<(), ReversibilityError> {
+        // Time at which the field of each action last matched.
+        let mut match_times = vec![playback.start_time; action_metas.len()];
+        let mut expected = expected.iter();
+
+        for (&time, &is_checkpoint) in playback.times.iter().zip(&playback.is_checkpoints) {
+            self.scrub(time);
+            if is_checkpoint == false {
+                continue;
+            }
+
+            let expected = expected.next().unwrap();
+            let actual = self.probe(check, action_metas);
+
+            for (a, action_meta) in action_metas.iter().enumerate() {
+                let (Some(expected), Some(actual)) = (&expected[a], &actual[a]) else {
+                    continue;
+                };
+                if is_settled(action_metas, action_meta, time) == false {
+                    continue;
+                }
+                if expected == actual {
+                    match_times[a] = time;
+                    continue;
+                }
+
+                let culprit = playback.culprit(action_metas, action_meta, match_times[a], time);
+                return Err(ReversibilityError {
+                    step_size: playback.step_size,
+                    direction: playback.direction,
+                    time,
+                    action_id: culprit.id(),
+                    action_type_name: culprit.action_type_name(),
+                    time_range: culprit.start_time..culprit.end_time(),
+                    expected: expected.clone(),
+                    actual: actual.clone(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Read the field of every [`ActionMeta`] that can be probed.
+    fn probe(
+        &mut self,
+        check: &ReversibilityCheck,
+        action_metas: &[ActionMeta],
+    ) -> Vec<Option<String>> {
+        let world = self.app.world_mut();
+        action_metas
+            .iter()
+            .map(|action_meta| {
+                let probe = check.probes.get(&action_meta.action_type_id())?;
+                probe(world, action_meta.id())
+            })
+            .collect()
+    }
+}
+
+/// Times that a [`SequenceHarness`] scrubs through in a single direction.
+struct Playback {
+    start_time: f32,
+    step_size: f32,
+    direction: PlaybackDirection,
+    times: Vec<f32>,
+    /// Whether the step to each time reached or crossed the start or end of an action.
+    is_checkpoints: Vec<bool>,
+}
+
+impl Playback {
+    fn new(
+        action_metas: &[ActionMeta],
+        start_time: f32,
+        end_time: f32,
+        step_size: f32,
+        direction: PlaybackDirection,
+    ) -> Self {
+        let mut times = Vec::new();
+        let mut time = start_time;
+        while time != end_time {
+            time = match direction {
+                PlaybackDirection::Forward => f32::min(time + step_size, end_time),
+                PlaybackDirection::Backward => f32::max(time - step_size, end_time),
+            };
+            times.push(time);
+        }
+
+        let mut prev_time = start_time;
+        let is_checkpoints = times
+            .iter()
+            .map(|&time| {
+                let range = f32::min(prev_time, time)..=f32::max(prev_time, time);
+                let is_checkpoint = time == end_time
+                    || action_metas.iter().any(|action_meta| {
+                        [action_meta.start_time, action_meta.end_time()]
+                            .iter()
+                            .any(|boundary| *boundary != prev_time && range.contains(boundary))
+                    });
+                prev_time = time;
+                is_checkpoint
+            })
+            .collect();
+
+        Self {
+            start_time,
+            step_size,
+            direction,
+            times,
+            is_checkpoints,
+        }
+    }
+
+    fn checkpoints(&self) -> impl Iterator<Item = f32> + '_ {
+        self.times
+            .iter()
+            .zip(&self.is_checkpoints)
+            .filter(|(_, is_checkpoint)| **is_checkpoint)
+            .map(|(time, _)| *time)
+    }
+
+    /// The last action in the direction of playback that wrote to the field of `diverged`
+    /// between the time it last matched and the time it diverged.
+    fn culprit<'a>(
+        &self,
+        action_metas: &'a [ActionMeta],
+        diverged: &'a ActionMeta,
+        match_time: f32,
+        time: f32,
+    ) -> &'a ActionMeta {
+        let (start, end) = (f32::min(match_time, time), f32::max(match_time, time));
+        let writers = action_metas.iter().filter(|action_meta| {
+            let is_overlap = match action_meta.duration > 0.0 {
+                true => action_meta.start_time < end && action_meta.end_time() > start,
+                false => (start..=end).contains(&action_meta.start_time),
+            };
+            is_overlap && is_same_field(action_meta, diverged)
+        });
+
+        let culprit = match self.direction {
+            PlaybackDirection::Forward => {
+                writers.max_by(|a, b| f32::total_cmp(&a.start_time, &b.start_time))
+            }
+            PlaybackDirection::Backward => {
+                writers.min_by(|a, b| f32::total_cmp(&a.end_time(), &b.end_time()))
+            }
+        };
+        culprit.unwrap_or(diverged)
+    }
+}
+
+/// Check if no action is in the middle of writing to the field of `action_meta` at `time`.
+fn is_settled(action_metas: &[ActionMeta], action_meta: &ActionMeta, time: f32) -> bool {
+    action_metas.iter().all(|other| {
+        (other.start_time < time && time < other.end_time()) == false
+            || is_same_field(other, action_meta) == false
+    })
+}
+
+/// Check if 2 actions may write to the same field.
+fn is_same_field(a: &ActionMeta, b: &ActionMeta) -> bool {
+    a.target_id() == b.target_id()
+        && a.target_type_id() == b.target_type_id()
+        && is_field_overlap(a.field_path(), b.field_path())
+}
+
+impl Default for SequenceHarness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Values read by a [`SequenceHarness`] paired with the time they were read at.
+///
+/// Displayed as one `time: value` line per sample with 4 decimal places,
+/// so small floating point differences do not break snapshots.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Samples<T>(pub Vec<(f32, T)>);
+
+impl<T: Clone> Samples<T> {
+    pub fn values(&self) -> Vec<T> {
+        self.0.iter().map(|(_, value)| value.clone()).collect()
+    }
+}
+
+impl<T: fmt::Debug> Samples<T> {
+    /// Compare against a snapshot file, see [`assert_snapshot`].
+    #[track_caller]
+    pub fn assert_snapshot(&self, path: impl AsRef<Path>) {
+        assert_snapshot(path, &self.to_string());
+    }
+}
+
+impl<T: fmt::Debug> fmt::Display for Samples<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (time, value) in &self.0 {
+            writeln!(f, "{time:.4}: {value:.4?}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Compare `actual` against the snapshot file at `path`.
+///
+/// The snapshot is written instead when the [`UPDATE_SNAPSHOTS_ENV`] environment variable is set.
+///
+/// # Panics
+///
+/// Panics if the snapshot is missing, differs, or cannot be read or written.
+#[track_caller]
+pub fn assert_snapshot(path: impl AsRef<Path>, actual: &str) {
+    let path = path.as_ref();
+
+    if std::env::var_os(UPDATE_SNAPSHOTS_ENV).is_some() {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .unwrap_or_else(|err| panic!("Failed to create {}: {err}", parent.display()));
+        }
+        std::fs::write(path, actual)
+            .unwrap_or_else(|err| panic!("Failed to write {}: {err}", path.display()));
+        return;
+    }
+
+    assert!(
+        path.exists(),
+        "Snapshot {} missing, set {UPDATE_SNAPSHOTS_ENV}=1 to record.",
+        path.display(),
+    );
+
+    let expected = std::fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("Failed to read {}: {err}", path.display()));
+
+    assert!(
+        expected.replace("\r\n", "\n") == actual,
+        "Snapshot {} does not match (set {UPDATE_SNAPSHOTS_ENV}=1 to update).\n\
+        --- expected\n{expected}\n+++ actual\n{actual}",
+        path.display(),
+    );
+}
+
+/// Type-erased function that formats the current value of the field an [`Action`] writes to.
+type ProbeFn = fn(world: &mut World, action_id: Entity) -> Option<String>;
+
+/// Step sizes and field probes for [`SequenceHarness::check_reversibility`].
+///
+/// Only [`Action`] types with a registered probe are checked.
+/// Values are compared through their [`Debug`](fmt::Debug) output, which is exact for floats.
+pub struct ReversibilityCheck {
+    step_sizes: Vec<f32>,
+    probes: HashMap<TypeId, ProbeFn>,
+}
+
+impl ReversibilityCheck {
+    /// Creates a new [`ReversibilityCheck`], step sizes larger than the [`Sequence`] jump across it at once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any step size is not positive.
+    pub fn new(step_sizes: impl IntoIterator<Item = f32>) -> Self {
+        let step_sizes = step_sizes.into_iter().collect::<Vec<_>>();
+        assert!(
+            step_sizes.iter().all(|&step_size| step_size > 0.0),
+            "Step sizes must be positive."
+        );
+
+        Self {
+            step_sizes,
+            probes: HashMap::default(),
+        }
+    }
+
+    /// Check [`Action<T, U>`] where `U` is a [`Component`].
+    pub fn component<U, T>(self) -> Self
+    where
+        U: Component,
+        T: fmt::Debug + Send + Sync + 'static,
+    {
+        self.probe::<U, T>(probe_component::<U, T>)
+    }
+
+    /// Check [`Action<T, U>`] where `U` is an [`Asset`].
+    pub fn asset<U, T>(self) -> Self
+    where
+        U: Asset,
+        T: fmt::Debug + Send + Sync + 'static,
+    {
+        self.probe::<U, T>(probe_asset::<U, T>)
+    }
+
+    /// Check [`Action<T, U>`] where `U` is a [`Resource`].
+    pub fn resource<U, T>(self) -> Self
+    where
+        U: Resource,
+        T: fmt::Debug + Send + Sync + 'static,
+    {
+        self.probe::<U, T>(probe_resource::<U, T>)
+    }
+
+    fn probe<U, T>(mut self, probe_fn: ProbeFn) -> Self
+    where
+        U: 'static,
+        T: 'static,
+    {
+        self.probes.insert(TypeId::of::<Action<T, U>>(), probe_fn);
+        self
+    }
+}
+
+/// The [`Action`] that made a field diverge while playing through a [`Sequence`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReversibilityError {
+    pub step_size: f32,
+    /// Direction of the playback that diverged.
+    pub direction: PlaybackDirection,
+    /// Time at which the divergence was found.
+    pub time: f32,
+    pub action_id: Entity,
+    pub action_type_name: &'static str,
+    /// Time range of the [`Action`] inside the [`Sequence`].
+    pub time_range: Range<f32>,
+    /// Value after seeking.
+    pub expected: String,
+    /// Value after playing with `step_size`.
+    pub actual: String,
+}
+
+impl fmt::Display for ReversibilityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` ({}, {:?}..{:?}) diverged at {:?} playing {:?} with a step size of {:?}: expected {}, got {}.",
+            self.action_type_name,
+            self.action_id,
+            self.time_range.start,
+            self.time_range.end,
+            self.time,
+            self.direction,
+            self.step_size,
+            self.expected,
+            self.actual,
+        )
+    }
+}
+
+impl std::error::Error for ReversibilityError {}
+
+fn probe_component<U, T>(world: &mut World, action_id: Entity) -> Option<String>
+where
+    U: Component,
+    T: fmt::Debug + Send + Sync + 'static,
+{
+    let action = world.get::<Action<T, U>>(action_id)?;
+    let target_id = action.target_id;
+    let get_field_fn = action.get_field_fn.clone();
+
+    let mut component = world.get_mut::<U>(target_id)?;
+    Some(format!(
+        "{:?}",
+        get_field_fn.get_mut(component.bypass_change_detection())
+    ))
+}
+
+fn probe_asset<U, T>(world: &mut World, action_id: Entity) -> Option<String>
+where
+    U: Asset,
+    T: fmt::Debug + Send + Sync + 'static,
+{
+    let action = world.get::<Action<T, U>>(action_id)?;
+    let target_id = action.target_id;
+    let get_field_fn = action.get_field_fn.clone();
+
+    let handle = world.get::<Handle<U>>(target_id)?.clone_weak();
+    let mut assets = world.get_resource_mut::<Assets<U>>()?;
+    let asset = assets.bypass_change_detection().get_mut(&handle)?;
+    Some(format!("{:?}", get_field_fn.get_mut(asset)))
+}
+
+fn probe_resource<U, T>(world: &mut World, action_id: Entity) -> Option<String>
+where
+    U: Resource,
+    T: fmt::Debug + Send + Sync + 'static,
+{
+    let action = world.get::<Action<T, U>>(action_id)?;
+    let get_field_fn = action.get_field_fn.clone();
+
+    let mut resource = world.get_resource_mut::<U>()?;
+    Some(format!(
+        "{:?}",
+        get_field_fn.get_mut(resource.bypass_change_detection())
+    ))
+}
@@ -1,8 +1,8 @@
 pub use bevy_vello_graphics;
 
 use bevy::{math::DVec2, prelude::*};
-use bevy_vello_graphics::prelude::*;
-use motiongfx_core::{sequence::update_component, UpdateSequenceSet};
+use bevy_vello_graphics::{bevy_vello::vello::peniko, prelude::*};
+use motiongfx_core::registry::MotionAppExt;
 
 pub mod motion;
 // pub mod svg;
@@ -17,28 +17,25 @@ pub struct MotionGfxVelloPlugin;
 
 impl Plugin for MotionGfxVelloPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(VelloGraphicsPlugin).add_systems(
-            Update,
-            (
-                // Fill & Stroke
-                update_component::<Fill, Brush>,
-                update_component::<Stroke, Brush>,
-                update_component::<Stroke, f64>,
-                // VelloCircle
-                update_component::<VelloCircle, VelloCircle>,
-                update_component::<VelloCircle, f64>,
-                // VelloRect
-                update_component::<VelloRect, VelloRect>,
-                update_component::<VelloRect, DVec2>,
-                update_component::<VelloRect, f64>,
-                // VelloLine
-                update_component::<VelloLine, VelloLine>,
-                update_component::<VelloLine, DVec2>,
-                update_component::<VelloLine, f64>,
-                // VelloBezPath
-                update_component::<VelloBezPath, f32>,
-            )
-                .in_set(UpdateSequenceSet),
-        );
+        app.add_plugins(VelloGraphicsPlugin)
+            // Fill & Stroke
+            .register_motion::<Fill, Brush>()
+            .register_motion::<Fill, peniko::Brush>()
+            .register_motion::<Stroke, Brush>()
+            .register_motion::<Stroke, peniko::Brush>()
+            .register_motion::<Stroke, f64>()
+            // VelloCircle
+            .register_motion::<VelloCircle, VelloCircle>()
+            .register_motion::<VelloCircle, f64>()
+            // VelloRect
+            .register_motion::<VelloRect, VelloRect>()
+            .register_motion::<VelloRect, DVec2>()
+            .register_motion::<VelloRect, f64>()
+            // VelloLine
+            .register_motion::<VelloLine, VelloLine>()
+            .register_motion::<VelloLine, DVec2>()
+            .register_motion::<VelloLine, f64>()
+            // VelloBezPath
+            .register_motion::<VelloBezPath, f32>();
     }
 }
@@ -6,9 +6,8 @@ fn main() {
         // Bevy plugins
         .add_plugins(DefaultPlugins)
         // Custom plugins
-        .add_plugins(MotionGfxPlugin)
+        .add_plugins((MotionGfxPlugin, TimelineInputPlugin))
         .add_systems(Startup, (setup, easings))
-        .add_systems(Update, timeline_movement)
         .run();
 }
 
@@ -24,6 +23,7 @@ fn easings(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>) {
         ease::circ::ease_in_out,
         ease::back::ease_in_out,
         ease::elastic::ease_in_out,
+        ease::bounce::ease_in_out,
     ];
 
     let capacity = easings.len();
@@ -70,7 +70,7 @@ fn easings(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>) {
                     let x = s.transform().transform.translation.x;
                     s.transform()
                         .to_translation_x(x + 10.0)
-                        .with_ease(e)
+                        .with_ease_fn(e)
                         .animate(1.0)
                 })
                 .add_motion(
@@ -103,31 +103,3 @@ fn setup(mut commands: Commands) {
         })
         .insert(BloomSettings::default());
 }
-
-fn timeline_movement(
-    mut q_timelines: Query<(&mut SequencePlayer, &mut SequenceController)>,
-    keys: Res<ButtonInput<KeyCode>>,
-    time: Res<Time>,
-) {
-    for (mut sequence_player, mut sequence_time) in q_timelines.iter_mut() {
-        if keys.pressed(KeyCode::KeyD) {
-            sequence_time.target_time += time.delta_seconds();
-        }
-
-        if keys.pressed(KeyCode::KeyA) {
-            sequence_time.target_time -= time.delta_seconds();
-        }
-
-        if keys.just_pressed(KeyCode::Space) {
-            if keys.pressed(KeyCode::ShiftLeft) {
-                sequence_player.time_scale = -1.0;
-            } else {
-                sequence_player.time_scale = 1.0;
-            }
-        }
-
-        if keys.just_pressed(KeyCode::Escape) {
-            sequence_player.time_scale = 0.0;
-        }
-    }
-}
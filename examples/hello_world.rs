@@ -6,9 +6,8 @@ fn main() {
         // Bevy plugins
         .add_plugins(DefaultPlugins)
         // Custom plugins
-        .add_plugins(MotionGfxPlugin)
+        .add_plugins((MotionGfxPlugin, TimelineInputPlugin))
         .add_systems(Startup, (setup, hello_world))
-        .add_systems(Update, timeline_movement)
         .run();
 }
 
@@ -56,6 +55,12 @@ fn hello_world(
         }
     }
 
+    // Grid positions for the stagger
+    let positions = cubes
+        .iter()
+        .map(|(_, transform)| transform.translation)
+        .collect::<Vec<_>>();
+
     // Generate sequence
     let mut cube_seqs = Vec::with_capacity(CAPACITY);
 
@@ -70,14 +75,14 @@ fn hello_world(
                 .add_motion(
                     cube.transform()
                         .to_scale(Vec3::splat(0.9))
-                        .with_ease(circ_ease)
+                        .with_ease_fn(circ_ease)
                         .animate(1.0),
                 )
                 .add_motion({
                     let x = cube.transform().transform.translation.x;
                     cube.transform()
                         .to_translation_x(x + 1.0)
-                        .with_ease(circ_ease)
+                        .with_ease_fn(circ_ease)
                         .animate(1.0)
                 })
                 .add_motion(
@@ -88,7 +93,7 @@ fn hello_world(
                             f32::to_radians(90.0),
                             0.0,
                         ))
-                        .with_ease(circ_ease)
+                        .with_ease_fn(circ_ease)
                         .animate(1.0),
                 )
                 .all();
@@ -97,7 +102,12 @@ fn hello_world(
         }
     }
 
-    let sequence = cube_seqs.flow(0.01);
+    // Ripple outwards from the center of the grid
+    let sequence = cube_seqs.stagger(
+        &Stagger::new(1.0)
+            .from_point(positions, Vec3::new(-1.5, -0.5, 0.0))
+            .with_ease_fn(ease::quad::ease_out),
+    );
 
     commands.spawn(SequencePlayerBundle {
         sequence,
@@ -123,31 +133,3 @@ fn setup(mut commands: Commands) {
         ..default()
     });
 }
-
-fn timeline_movement(
-    mut q_timelines: Query<(&mut SequencePlayer, &mut SequenceController)>,
-    keys: Res<ButtonInput<KeyCode>>,
-    time: Res<Time>,
-) {
-    for (mut sequence_player, mut sequence_time) in q_timelines.iter_mut() {
-        if keys.pressed(KeyCode::KeyD) {
-            sequence_time.target_time += time.delta_seconds();
-        }
-
-        if keys.pressed(KeyCode::KeyA) {
-            sequence_time.target_time -= time.delta_seconds();
-        }
-
-        if keys.just_pressed(KeyCode::Space) {
-            if keys.pressed(KeyCode::ShiftLeft) {
-                sequence_player.time_scale = -1.0;
-            } else {
-                sequence_player.time_scale = 1.0;
-            }
-        }
-
-        if keys.just_pressed(KeyCode::Escape) {
-            sequence_player.time_scale = 0.0;
-        }
-    }
-}
@@ -2,13 +2,17 @@ use bevy::{core_pipeline::bloom::BloomSettings, pbr::NotShadowCaster, prelude::*
 use bevy_motiongfx::{prelude::*, MotionGfxPlugin};
 
 fn main() {
+    // Advance the slides with space as well as the default arrow keys.
+    let mut timeline_input = TimelineInput::default();
+    timeline_input.bind(TimelineControl::NextSlide, KeyCode::Space);
+
     App::new()
         // Bevy plugins
         .add_plugins(DefaultPlugins)
         // Custom plugins
-        .add_plugins(MotionGfxPlugin)
+        .add_plugins((MotionGfxPlugin, TimelineInputPlugin))
+        .insert_resource(timeline_input)
         .add_systems(Startup, (setup, slide_basic))
-        .add_systems(Update, slide_movement)
         .run();
 }
 
@@ -96,21 +100,3 @@ fn setup(mut commands: Commands) {
         ..default()
     });
 }
-
-fn slide_movement(mut q_slides: Query<&mut SlideController>, keys: Res<ButtonInput<KeyCode>>) {
-    for mut slide in q_slides.iter_mut() {
-        if keys.just_pressed(KeyCode::Space) {
-            slide.set_time_scale(1.0);
-
-            if keys.pressed(KeyCode::ShiftLeft) {
-                slide.prev();
-            } else {
-                slide.next();
-            }
-        }
-
-        if keys.just_pressed(KeyCode::Escape) {
-            slide.set_time_scale(0.0);
-        }
-    }
-}
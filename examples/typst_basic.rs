@@ -11,10 +11,10 @@ fn main() {}
 //         .add_plugins((
 //             MotionGfxPlugin,
 //             MotionGfxVelloPlugin,
+//             TimelineInputPlugin,
 //             TypstCompilerPlugin::new(Vec::new()),
 //         ))
 //         .add_systems(Startup, (setup, typst_basic))
-//         .add_systems(Update, timeline_movement)
 //         .run();
 // }
 
@@ -119,7 +119,7 @@ fn main() {}
 //             }
 
 //             let sequence = all(&[all(&setup_seqs), flow(0.1, &animate_seqs)])
-//                 .with_ease(ease::expo::ease_in_out);
+//                 .with_ease_fn(ease::expo::ease_in_out);
 
 //             commands.spawn(SequencePlayerBundle {
 //                 sequence,
@@ -135,31 +135,3 @@ fn main() {}
 // fn setup(mut commands: Commands) {
 //     commands.spawn(Camera2dBundle::default());
 // }
-
-// fn timeline_movement(
-//     mut q_timelines: Query<(&mut SequencePlayer, &mut SequenceController)>,
-//     keys: Res<ButtonInput<KeyCode>>,
-//     time: Res<Time>,
-// ) {
-//     for (mut sequence_player, mut sequence_time) in q_timelines.iter_mut() {
-//         if keys.pressed(KeyCode::KeyD) {
-//             sequence_time.target_time += time.delta_seconds();
-//         }
-
-//         if keys.pressed(KeyCode::KeyA) {
-//             sequence_time.target_time -= time.delta_seconds();
-//         }
-
-//         if keys.just_pressed(KeyCode::Space) {
-//             if keys.pressed(KeyCode::ShiftLeft) {
-//                 sequence_player.time_scale = -1.0;
-//             } else {
-//                 sequence_player.time_scale = 1.0;
-//             }
-//         }
-
-//         if keys.just_pressed(KeyCode::Escape) {
-//             sequence_player.time_scale = 0.0;
-//         }
-//     }
-// }